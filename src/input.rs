@@ -10,6 +10,9 @@ use eframe::egui;
 pub struct EguiInput {
     current_dir: Direction,
     last_settled_dir: Direction,
+    /// Step-by-step mode: the frontend only advances the game after a key event
+    pub step_mode: bool,
+    pending_input: bool,
 }
 
 impl EguiInput {
@@ -17,6 +20,8 @@ impl EguiInput {
         Self {
             current_dir: initial_dir,
             last_settled_dir: initial_dir,
+            step_mode: false,
+            pending_input: false,
         }
     }
 
@@ -43,6 +48,7 @@ impl EguiInput {
         };
 
         if let Some(dir) = requested_dir {
+            self.pending_input = true;
             // Prevent 180-degree reversal
             if !self.is_opposite(dir, self.last_settled_dir) {
                 self.current_dir = dir;
@@ -53,6 +59,12 @@ impl EguiInput {
     /// Mark current direction as settled (call after each game step)
     pub fn settle(&mut self) {
         self.last_settled_dir = self.current_dir;
+        self.pending_input = false;
+    }
+
+    /// Whether a direction key was pressed since the last `settle`
+    pub fn has_pending_input(&self) -> bool {
+        self.pending_input
     }
 
     fn is_opposite(&self, dir1: Direction, dir2: Direction) -> bool {
@@ -70,4 +82,111 @@ impl Input for EguiInput {
     fn current_dir(&self) -> Direction {
         self.current_dir
     }
+
+    fn has_input(&self) -> bool {
+        self.pending_input
+    }
+}
+
+/// Input that replays a recorded direction sequence, one entry per step
+///
+/// The frontend or loop calls `advance` after each step; past the end the last direction
+/// is held. An empty recording keeps the starting heading (`Right`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlaybackInput {
+    directions: Vec<Direction>,
+    index: usize,
+}
+
+impl PlaybackInput {
+    pub fn new(directions: Vec<Direction>) -> Self {
+        Self { directions, index: 0 }
+    }
+
+    /// Move on to the next recorded direction (no-op once on the last one)
+    pub fn advance(&mut self) {
+        if self.index + 1 < self.directions.len() {
+            self.index += 1;
+        }
+    }
+}
+
+impl Input for PlaybackInput {
+    fn current_dir(&self) -> Direction {
+        self.directions.get(self.index).copied().unwrap_or(Direction::Right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run one egui frame with `keys` pressed and feed it to `input`
+    fn frame_with_keys(input: &mut EguiInput, keys: &[egui::Key]) {
+        let ctx = egui::Context::default();
+        let events = keys
+            .iter()
+            .map(|&key| egui::Event::Key {
+                key,
+                physical_key: None,
+                pressed: true,
+                repeat: false,
+                modifiers: egui::Modifiers::NONE,
+            })
+            .collect();
+        ctx.begin_frame(egui::RawInput { events, ..Default::default() });
+        input.update(&ctx);
+        let _ = ctx.end_frame();
+    }
+
+    #[test]
+    fn test_pending_input_set_by_key_press() {
+        let mut input = EguiInput::new(Direction::Right);
+        assert!(!input.has_pending_input());
+
+        frame_with_keys(&mut input, &[egui::Key::ArrowDown]);
+        assert!(input.has_pending_input());
+        assert_eq!(input.current_dir(), Direction::Down);
+
+        input.settle();
+        assert!(!input.has_pending_input());
+    }
+
+    #[test]
+    fn test_playback_returns_directions_in_order_then_holds_last() {
+        let mut input = PlaybackInput::new(vec![Direction::Up, Direction::Left, Direction::Down]);
+
+        let mut seen = Vec::new();
+        for _ in 0..5 {
+            seen.push(input.current_dir());
+            input.advance();
+        }
+
+        assert_eq!(
+            seen,
+            [Direction::Up, Direction::Left, Direction::Down, Direction::Down, Direction::Down]
+        );
+        assert!(input.has_input());
+    }
+
+    #[test]
+    fn test_empty_playback_keeps_starting_heading() {
+        let mut input = PlaybackInput::new(Vec::new());
+        input.advance();
+        assert_eq!(input.current_dir(), Direction::Right);
+    }
+
+    #[test]
+    fn test_no_pending_input_without_key_press() {
+        let mut input = EguiInput::new(Direction::Right);
+        input.step_mode = true;
+
+        frame_with_keys(&mut input, &[]);
+        assert!(!input.has_pending_input());
+        assert_eq!(input.current_dir(), Direction::Right, "heading is kept");
+
+        // Keys that aren't directions don't count either
+        frame_with_keys(&mut input, &[egui::Key::Space]);
+        assert!(!input.has_pending_input());
+    }
 }
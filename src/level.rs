@@ -0,0 +1,73 @@
+//! Level generation: procedurally placed obstacles
+//!
+//! Generators only decide where obstacles go; they are pure functions of the
+//! grid, RNG and parameters so levels are reproducible from a seed.
+
+use crate::{rng::RngLike, types::*};
+use std::collections::VecDeque;
+
+/// Randomly mark roughly `density` of the grid as obstacles.
+///
+/// The start cell (grid center) and its four neighbors are always left free,
+/// and an obstacle is only kept if every free cell stays reachable from the
+/// start, so the board never splits into sealed-off pockets.
+pub fn generate_maze<R: RngLike>(grid: GridSize, rng: &mut R, density: f32) -> Vec<Position> {
+    let start = grid.center();
+    let protected = [
+        start,
+        Position { x: start.x, y: start.y - 1 },
+        Position { x: start.x, y: start.y + 1 },
+        Position { x: start.x - 1, y: start.y },
+        Position { x: start.x + 1, y: start.y },
+    ];
+
+    let mut candidates: Vec<Position> = (0..grid.h)
+        .flat_map(|y| (0..grid.w).map(move |x| Position { x, y }))
+        .filter(|p| !protected.contains(p))
+        .collect();
+    let target = (density.clamp(0.0, 1.0) * candidates.len() as f32).round() as usize;
+
+    let mut blocked = vec![false; grid.area()];
+    let mut obstacles = Vec::with_capacity(target);
+    while obstacles.len() < target && !candidates.is_empty() {
+        let pick = (rng.next_u32() as usize) % candidates.len();
+        let p = candidates.swap_remove(pick);
+        let idx = cell_index(p, grid);
+        blocked[idx] = true;
+        if is_connected(grid, &blocked, start) {
+            obstacles.push(p);
+        } else {
+            blocked[idx] = false;
+        }
+    }
+    obstacles
+}
+
+fn cell_index(p: Position, grid: GridSize) -> usize {
+    (p.y * grid.w + p.x) as usize
+}
+
+/// Flood fill from `start`: are all unblocked cells reachable?
+fn is_connected(grid: GridSize, blocked: &[bool], start: Position) -> bool {
+    let free = blocked.iter().filter(|&&b| !b).count();
+    let mut seen = vec![false; blocked.len()];
+    let mut queue = VecDeque::from([start]);
+    seen[cell_index(start, grid)] = true;
+    let mut reached = 0;
+
+    while let Some(p) = queue.pop_front() {
+        reached += 1;
+        for (dx, dy) in [(0, -1), (0, 1), (-1, 0), (1, 0)] {
+            let n = Position { x: p.x + dx, y: p.y + dy };
+            if n.x < 0 || n.y < 0 || n.x >= grid.w || n.y >= grid.h {
+                continue;
+            }
+            let idx = cell_index(n, grid);
+            if !seen[idx] && !blocked[idx] {
+                seen[idx] = true;
+                queue.push_back(n);
+            }
+        }
+    }
+    reached == free
+}
@@ -1,7 +1,15 @@
+#[cfg(feature = "gui")]
+pub mod input;
+pub mod level;
+pub mod menu;
 pub mod rng;
 pub mod rules;
+pub mod sim;
 pub mod state;
 pub mod systems;
 pub mod types;
 pub mod settings;
 pub mod persistence;
+pub mod profile;
+#[cfg(feature = "gui")]
+pub mod render;
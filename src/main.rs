@@ -1,14 +1,13 @@
-mod input;
-mod render;
-mod rng;
-mod rules;
-mod state;
-mod systems;
-mod types;
-
 use eframe::egui;
-use systems::{Loop, Time};
-use types::Tick;
+use snake_game::{
+    input,
+    menu::{MenuItem, MenuState},
+    render,
+    rng::{self, RngLike},
+    state,
+    systems::{Loop, RealTime},
+    types,
+};
 
 fn main() {
     let native_options = eframe::NativeOptions::default();
@@ -20,103 +19,166 @@ fn main() {
     .expect("Failed to run application");
 }
 
-/// Simple Time implementation for egui app
-struct EguiTime {
-    current_tick: u64,
-}
+/// Time between game steps (~6.67 fps)
+const STEP_INTERVAL: std::time::Duration = std::time::Duration::from_millis(150);
 
-impl EguiTime {
-    fn new() -> Self {
-        Self { current_tick: 0 }
-    }
-}
-
-impl Time for EguiTime {
-    fn tick(&mut self) -> Tick {
-        self.current_tick += 1;
-        Tick(self.current_tick)
-    }
-}
+/// Steps the snake holds still at the start of each round ("3..2..1")
+const COUNTDOWN_TICKS: u8 = 3;
 
 struct SnakeApp {
     game_state: state::GameState,
     input: input::EguiInput,
-    loop_system: Loop<input::EguiInput, EguiTime, rng::Seeded>,
-    last_update_time: std::time::Instant,
-    update_interval: std::time::Duration,
+    loop_system: Loop<input::EguiInput, RealTime, rng::Seeded>,
+    render_options: render::RenderOptions,
+    palette: snake_game::settings::Palette,
+    menu: MenuState,
 }
 
 impl Default for SnakeApp {
     fn default() -> Self {
         let grid = types::GridSize { w: 20, h: 20 };
-        let rng = rng::Seeded::new(42);
-        let game_state = state::GameState::new(grid, rng.clone());
+        let seed = 42;
+        let rng = rng::Seeded::new(seed);
+        let mut game_state = state::GameState::from_seed(grid, seed);
+        game_state.start_countdown(COUNTDOWN_TICKS);
 
         let initial_dir = game_state.snake.dir;
         let input = input::EguiInput::new(initial_dir);
-        let time = EguiTime::new();
+        let time = RealTime::new(STEP_INTERVAL);
 
-        let loop_system = Loop {
-            input: input.clone(),
-            time,
-            rng,
-        };
+        let loop_system = Loop::new(input.clone(), time, rng);
 
         Self {
             game_state,
             input,
             loop_system,
-            last_update_time: std::time::Instant::now(),
-            update_interval: std::time::Duration::from_millis(150), // ~6.67 fps
+            render_options: render::RenderOptions {
+                hud: render::HudConfig { show_seed: true, ..Default::default() },
+                ..Default::default()
+            },
+            palette: snake_game::settings::Palette::Default,
+            menu: MenuState::None,
+        }
+    }
+}
+
+impl SnakeApp {
+    /// New round on a seed drawn from the loop's RNG, so the HUD can show it
+    fn restart(&mut self) {
+        let seed = self.loop_system.rng.next_u32() as u64;
+        self.game_state.reseed(seed);
+        self.game_state.start_countdown(COUNTDOWN_TICKS);
+        self.loop_system.rng = rng::Seeded::new(seed);
+        let step_mode = self.input.step_mode;
+        self.input = input::EguiInput::new(types::Direction::Right);
+        self.input.step_mode = step_mode;
+        self.loop_system.input = self.input.clone();
+    }
+
+    /// Arrow keys and Enter drive the pause menu while it is open
+    fn handle_menu_keys(&mut self, ctx: &egui::Context) {
+        let keys = [
+            (egui::Key::ArrowUp, types::Direction::Up),
+            (egui::Key::ArrowDown, types::Direction::Down),
+        ];
+        for (key, dir) in keys {
+            if ctx.input(|i| i.key_pressed(key)) {
+                self.menu.navigate(dir);
+            }
+        }
+        if ctx.input(|i| i.key_pressed(egui::Key::Enter)) {
+            match self.menu.activate() {
+                Some(MenuItem::Resume) => self.game_state.resume(),
+                Some(MenuItem::Restart) => self.restart(),
+                Some(MenuItem::Quit) => ctx.send_viewport_cmd(egui::ViewportCommand::Close),
+                None => {}
+            }
         }
     }
 }
 
 impl eframe::App for SnakeApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Update input
-        self.input.update(ctx);
+        // Update input; the pause menu owns the arrow keys while it is open
+        if self.menu == MenuState::None {
+            self.input.update(ctx);
+        } else {
+            self.handle_menu_keys(ctx);
+        }
 
         // Handle pause toggle
         if ctx.input(|i| i.key_pressed(egui::Key::Space)) {
             if self.game_state.is_paused() {
                 self.game_state.resume();
-            } else {
+                self.menu = MenuState::None;
+            } else if self.game_state.is_running() {
                 self.game_state.pause();
+                self.menu = MenuState::open();
             }
         }
 
         // Reset game
         if ctx.input(|i| i.key_pressed(egui::Key::R)) {
-            let rng = self.loop_system.rng.clone();
-            self.game_state.reset(rng.clone());
-            self.input = input::EguiInput::new(types::Direction::Right);
-            self.loop_system.rng = rng;
-            self.loop_system.input = self.input.clone();
+            self.restart();
+            self.menu = MenuState::None;
         }
 
-        // Update game loop at fixed interval
-        if !self.game_state.is_paused() && !self.game_state.is_over() {
-            let now = std::time::Instant::now();
-            if now.duration_since(self.last_update_time) >= self.update_interval {
-                self.loop_system.input = self.input.clone();
-                self.loop_system.update(&mut self.game_state);
-                self.input.settle();
-                self.last_update_time = now;
-            }
+        // Toggle snake color mode
+        if ctx.input(|i| i.key_pressed(egui::Key::C)) {
+            self.render_options.color_mode = match self.render_options.color_mode {
+                render::ColorMode::Solid => render::ColorMode::Gradient,
+                render::ColorMode::Gradient => render::ColorMode::Solid,
+            };
+        }
+
+        // Cycle color palette
+        if ctx.input(|i| i.key_pressed(egui::Key::P)) {
+            self.palette = self.palette.next();
+            self.render_options.theme = render::palette_colors(self.palette);
+        }
+
+        // Toggle coordinate overlay
+        if ctx.input(|i| i.key_pressed(egui::Key::F3)) {
+            self.render_options.debug_overlay = !self.render_options.debug_overlay;
+        }
+
+        // Toggle step-by-step mode
+        if ctx.input(|i| i.key_pressed(egui::Key::T)) {
+            self.input.step_mode = !self.input.step_mode;
+        }
+
+        // Update game loop at fixed interval (a countdown runs down through the loop too);
+        // step mode also waits for a direction key
+        let ready = !self.input.step_mode || self.input.has_pending_input();
+        let active = !self.game_state.is_finished() && !self.game_state.is_paused();
+        if active && ready && self.loop_system.time.pending() > 0 {
+            self.loop_system.input = self.input.clone();
+            self.loop_system.update(&mut self.game_state);
+            self.input.settle();
         }
 
         // Render
         egui::CentralPanel::default().show(ctx, |ui| {
             let available_rect = ui.max_rect();
-            let painter = ui.painter();
-
-            render::render_game(painter, available_rect, &self.game_state);
+            // Painter is a cheap handle onto the UI layer; the clone draws to the same place
+            let mut painter = ui.painter().clone();
+
+            render::render_game(
+                &mut painter,
+                available_rect,
+                &self.game_state,
+                &self.render_options,
+                ctx.input(|i| i.time),
+            );
+
+            if let MenuState::Paused { selection } = self.menu {
+                draw_pause_menu(ui, available_rect, selection);
+            }
 
             // Show controls
             ui.allocate_space(egui::vec2(0.0, available_rect.height() - 100.0));
             ui.horizontal(|ui| {
-                ui.label("Controls: Arrow Keys/WASD - Move | Space - Pause | R - Reset");
+                ui.label("Controls: Arrow Keys/WASD - Move | Space - Pause | R - Reset | C - Colors | P - Palette | T - Step mode | F3 - Coordinates");
             });
         });
 
@@ -124,3 +186,24 @@ impl eframe::App for SnakeApp {
         ctx.request_repaint();
     }
 }
+
+/// Centered list of pause menu entries, highlighting the current selection
+fn draw_pause_menu(ui: &egui::Ui, rect: egui::Rect, selection: MenuItem) {
+    let painter = ui.painter();
+    let row_height = 32.0;
+    let top = rect.center().y - row_height * (MenuItem::ALL.len() as f32 - 1.0) / 2.0;
+    for (i, item) in MenuItem::ALL.iter().enumerate() {
+        let (text, color) = if *item == selection {
+            (format!("> {} <", item.label()), egui::Color32::YELLOW)
+        } else {
+            (item.label().to_string(), egui::Color32::WHITE)
+        };
+        painter.text(
+            egui::pos2(rect.center().x, top + row_height * i as f32),
+            egui::Align2::CENTER_CENTER,
+            text,
+            egui::FontId::proportional(24.0),
+            color,
+        );
+    }
+}
@@ -0,0 +1,102 @@
+//! Pause menu state machine, kept free of egui so it can be tested directly
+
+use crate::types::Direction;
+
+/// Entries of the pause menu, top to bottom
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MenuItem {
+    Resume,
+    Restart,
+    Quit,
+}
+
+impl MenuItem {
+    pub const ALL: [MenuItem; 3] = [MenuItem::Resume, MenuItem::Restart, MenuItem::Quit];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MenuItem::Resume => "Resume",
+            MenuItem::Restart => "Restart",
+            MenuItem::Quit => "Quit",
+        }
+    }
+}
+
+/// Whether the pause menu is open, and which entry is highlighted
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MenuState {
+    #[default]
+    None,
+    Paused { selection: MenuItem },
+}
+
+impl MenuState {
+    /// Open the menu with `Resume` highlighted
+    pub fn open() -> Self {
+        MenuState::Paused { selection: MenuItem::Resume }
+    }
+
+    /// Move the highlight; no-op when the menu is closed
+    pub fn navigate(&mut self, dir: Direction) {
+        if let MenuState::Paused { selection } = self {
+            *selection = next_selection(*selection, dir);
+        }
+    }
+
+    /// Close the menu and return the chosen entry, or `None` if it wasn't open
+    pub fn activate(&mut self) -> Option<MenuItem> {
+        match std::mem::take(self) {
+            MenuState::Paused { selection } => Some(selection),
+            MenuState::None => None,
+        }
+    }
+}
+
+/// Entry highlighted after pressing `dir`: Up/Down move and wrap around, Left/Right do nothing
+pub fn next_selection(cur: MenuItem, dir: Direction) -> MenuItem {
+    let len = MenuItem::ALL.len();
+    let idx = MenuItem::ALL.iter().position(|&item| item == cur).unwrap_or(0);
+    match dir {
+        Direction::Up => MenuItem::ALL[(idx + len - 1) % len],
+        Direction::Down => MenuItem::ALL[(idx + 1) % len],
+        Direction::Left | Direction::Right => cur,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_selection_moves_down_and_wraps() {
+        assert_eq!(next_selection(MenuItem::Resume, Direction::Down), MenuItem::Restart);
+        assert_eq!(next_selection(MenuItem::Restart, Direction::Down), MenuItem::Quit);
+        assert_eq!(next_selection(MenuItem::Quit, Direction::Down), MenuItem::Resume);
+    }
+
+    #[test]
+    fn test_next_selection_moves_up_and_wraps() {
+        assert_eq!(next_selection(MenuItem::Resume, Direction::Up), MenuItem::Quit);
+        assert_eq!(next_selection(MenuItem::Quit, Direction::Up), MenuItem::Restart);
+    }
+
+    #[test]
+    fn test_next_selection_ignores_sideways() {
+        assert_eq!(next_selection(MenuItem::Restart, Direction::Left), MenuItem::Restart);
+        assert_eq!(next_selection(MenuItem::Restart, Direction::Right), MenuItem::Restart);
+    }
+
+    #[test]
+    fn test_menu_state_navigate_and_activate() {
+        let mut menu = MenuState::default();
+        menu.navigate(Direction::Down);
+        assert_eq!(menu, MenuState::None, "a closed menu ignores navigation");
+        assert_eq!(menu.activate(), None);
+
+        menu = MenuState::open();
+        menu.navigate(Direction::Down);
+        menu.navigate(Direction::Down);
+        assert_eq!(menu.activate(), Some(MenuItem::Quit));
+        assert_eq!(menu, MenuState::None, "activating closes the menu");
+    }
+}
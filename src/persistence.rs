@@ -3,6 +3,7 @@
 //! This module provides functionality to save and load high scores to/from JSON files.
 //! Uses serde for serialization to ensure round-trip compatibility.
 
+use crate::settings::SettingsError;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::fs;
@@ -16,6 +17,9 @@ pub struct HighScore {
     pub player_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<u64>,
+    /// Length of the game in ticks, for speedrun rankings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ticks: Option<u64>,
 }
 
 /// Collection of high scores, keyed by grid size for separate leaderboards
@@ -25,12 +29,24 @@ pub struct HighScores {
     pub scores: BTreeMap<String, Vec<HighScore>>,
 }
 
+impl HighScores {
+    /// Drop entries timestamped before `cutoff_epoch`; entries without a timestamp are kept
+    pub fn prune_older_than(&mut self, cutoff_epoch: u64) {
+        for entries in self.scores.values_mut() {
+            entries.retain(|hs| hs.timestamp.is_none_or(|t| t >= cutoff_epoch));
+        }
+        self.scores.retain(|_, entries| !entries.is_empty());
+    }
+}
+
 /// Errors that can occur during persistence operations
 #[derive(Debug, PartialEq, Eq)]
 pub enum PersistenceError {
     IoError(String),
     SerializationError(String),
     DeserializationError(String),
+    /// The file parsed but holds settings that fail validation
+    InvalidSettings(SettingsError),
 }
 
 impl From<std::io::Error> for PersistenceError {
@@ -58,6 +74,21 @@ impl HighScoreStore {
         Ok(Self { path, scores })
     }
 
+    /// Like `new`, but a file that isn't valid high-score JSON is moved aside to
+    /// `<file name>.corrupt.bak` and the store starts empty instead of failing
+    pub fn new_recovering<P: AsRef<Path>>(path: P) -> Result<Self, PersistenceError> {
+        match Self::new(path.as_ref()) {
+            Err(PersistenceError::DeserializationError(_)) => {
+                let mut backup = path.as_ref().as_os_str().to_owned();
+                backup.push(".corrupt.bak");
+                fs::rename(path.as_ref(), &backup)
+                    .map_err(|e| PersistenceError::IoError(format!("Failed to back up corrupt file: {}", e)))?;
+                Self::new(path)
+            }
+            result => result,
+        }
+    }
+
     /// Load high scores from a file path
     fn load_from_path<P: AsRef<Path>>(path: P) -> Result<HighScores, PersistenceError> {
         let contents = fs::read_to_string(path.as_ref())
@@ -100,18 +131,59 @@ impl HighScoreStore {
 
     /// Add a high score entry for a given grid size key
     pub fn add_score(&mut self, grid_key: String, score: HighScore) {
-        let entry = self.scores.scores.entry(grid_key).or_insert_with(Vec::new);
+        let entry = self.scores.scores.entry(grid_key).or_default();
         entry.push(score);
         // Sort in descending order (highest first) and keep top 10
-        entry.sort_by(|a, b| b.score.cmp(&a.score));
+        entry.sort_by_key(|hs| std::cmp::Reverse(hs.score));
         entry.truncate(10);
     }
 
+    /// Add a score and immediately persist the store; returns whether it made the top 10
+    pub fn add_score_and_save(&mut self, grid_key: String, score: HighScore) -> Result<bool, PersistenceError> {
+        let existing = self.get_scores(&grid_key);
+        // Ties sort after existing entries, so a full board needs a strictly higher score
+        let made_top = existing.len() < 10 || existing.last().is_some_and(|last| score.score > last.score);
+        self.add_score(grid_key, score);
+        self.save()?;
+        Ok(made_top)
+    }
+
+    /// Drop entries timestamped before `cutoff_epoch` (see `HighScores::prune_older_than`)
+    pub fn prune_older_than(&mut self, cutoff_epoch: u64) {
+        self.scores.prune_older_than(cutoff_epoch);
+    }
+
     /// Get the highest score for a given grid size key
     pub fn get_highest_score(&self, grid_key: &str) -> Option<u32> {
         self.get_scores(grid_key).first().map(|hs| hs.score)
     }
 
+    /// Get the entry with the shortest recorded duration; entries without one are ignored
+    pub fn get_fastest(&self, grid_key: &str) -> Option<&HighScore> {
+        self.get_scores(grid_key)
+            .iter()
+            .filter(|hs| hs.duration_ticks.is_some())
+            .min_by_key(|hs| hs.duration_ticks)
+    }
+
+    /// `player`'s entries on every grid, in grid key order then score order
+    pub fn scores_by_player(&self, player: &str) -> Vec<(&str, &HighScore)> {
+        self.scores
+            .scores
+            .iter()
+            .flat_map(|(key, entries)| entries.iter().map(move |hs| (key.as_str(), hs)))
+            .filter(|(_, hs)| hs.player_name.as_deref() == Some(player))
+            .collect()
+    }
+
+    /// `player`'s highest-scoring entry on any grid; ties go to the first in grid key order
+    pub fn personal_best(&self, player: &str) -> Option<&HighScore> {
+        self.scores_by_player(player)
+            .into_iter()
+            .map(|(_, hs)| hs)
+            .min_by_key(|hs| std::cmp::Reverse(hs.score))
+    }
+
     /// Check if a score qualifies as a high score for the given grid size
     pub fn is_high_score(&self, grid_key: &str, score: u32) -> bool {
         match self.get_highest_score(grid_key) {
@@ -144,6 +216,7 @@ mod tests {
             score: 100,
             player_name: Some("Player1".to_string()),
             timestamp: Some(1234567890),
+            duration_ticks: None,
         };
         assert_eq!(score.score, 100);
     }
@@ -163,12 +236,14 @@ mod tests {
             score: 50,
             player_name: None,
             timestamp: None,
+            duration_ticks: None,
         });
         
         store.add_score(key.clone(), HighScore {
             score: 100,
             player_name: None,
             timestamp: None,
+            duration_ticks: None,
         });
 
         let scores = store.get_scores(&key);
@@ -187,6 +262,7 @@ mod tests {
                 score: i * 10,
                 player_name: None,
                 timestamp: None,
+                duration_ticks: None,
             });
         }
 
@@ -206,6 +282,7 @@ mod tests {
             score: 50,
             player_name: None,
             timestamp: None,
+            duration_ticks: None,
         });
         
         assert!(store.is_high_score(&key, 100));
@@ -229,11 +306,13 @@ mod tests {
             score: 100,
             player_name: Some("Alice".to_string()),
             timestamp: Some(1234567890),
+            duration_ticks: None,
         });
         store1.add_score("10x10".to_string(), HighScore {
             score: 75,
             player_name: Some("Bob".to_string()),
             timestamp: Some(1234567891),
+            duration_ticks: None,
         });
         store1.save().unwrap();
 
@@ -256,12 +335,14 @@ mod tests {
             score: 50,
             player_name: None,
             timestamp: None,
+            duration_ticks: None,
         });
         
         store.add_score("20x20".to_string(), HighScore {
             score: 100,
             player_name: None,
             timestamp: None,
+            duration_ticks: None,
         });
 
         assert_eq!(store.get_scores("10x10")[0].score, 50);
@@ -280,6 +361,7 @@ mod tests {
                 score: i,
                 player_name: None,
                 timestamp: None,
+                duration_ticks: None,
             });
         }
 
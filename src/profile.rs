@@ -0,0 +1,62 @@
+//! Player profiles: game settings and theme saved together in one JSON file
+
+use crate::persistence::PersistenceError;
+use crate::settings::{Palette, Settings};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Everything a player customizes, stored as a single file
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Profile {
+    pub settings: Settings,
+    /// Name of a `Palette` (see `Palette::name`)
+    pub theme_name: String,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self {
+            settings: Settings::default(),
+            theme_name: Palette::default().name().to_string(),
+        }
+    }
+}
+
+impl Profile {
+    /// Palette named by `theme_name`, or the default palette for unknown names
+    pub fn palette(&self) -> Palette {
+        Palette::from_name(&self.theme_name).unwrap_or_default()
+    }
+
+    /// Load a profile, rejecting invalid settings; unknown theme names fall back to the default
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, PersistenceError> {
+        let contents = fs::read_to_string(path.as_ref())
+            .map_err(|e| PersistenceError::IoError(format!("Failed to read file: {}", e)))?;
+
+        let mut profile: Profile = serde_json::from_str(&contents)
+            .map_err(|e| PersistenceError::DeserializationError(format!("Invalid JSON: {}", e)))?;
+
+        profile.settings.validate().map_err(PersistenceError::InvalidSettings)?;
+        if Palette::from_name(&profile.theme_name).is_none() {
+            profile.theme_name = Palette::default().name().to_string();
+        }
+        Ok(profile)
+    }
+
+    /// Save the profile as pretty-printed JSON, creating parent directories as needed
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), PersistenceError> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| PersistenceError::SerializationError(format!("Failed to serialize: {}", e)))?;
+
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| PersistenceError::IoError(format!("Failed to create directory: {}", e)))?;
+        }
+
+        fs::write(path.as_ref(), json)
+            .map_err(|e| PersistenceError::IoError(format!("Failed to write file: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -1,45 +1,307 @@
-//! Rendering module: draws grid, snake, food, and HUD using egui::Painter
+//! Rendering module: draws grid, snake, food, and HUD through a `Renderer` backend
+//! (egui's `Painter` out of the box)
 
-use crate::{state::GameState, types::*};
+use crate::{
+    settings::Palette,
+    state::{GameOverReason, GameState, RunState, WinReason},
+    types::*,
+};
 #[cfg(feature = "multiple_foods")]
 use crate::types::{Food, FoodType};
-use eframe::egui::{self, Color32, Painter, Rect, Stroke, Style, TextStyle};
+use eframe::egui::{self, Color32, Painter, Pos2, Rect, Stroke, Style, TextStyle};
 
-const CELL_MARGIN: f32 = 1.0;
-const GRID_COLOR: Color32 = Color32::from_rgb(40, 40, 40);
-const SNAKE_COLOR: Color32 = Color32::from_rgb(0, 200, 0);
-const FOOD_COLOR: Color32 = Color32::from_rgb(200, 0, 0);
-const HEAD_COLOR: Color32 = Color32::from_rgb(0, 255, 0);
+/// Translucent gold laid over the bonus zone
+const BONUS_ZONE_TINT: Color32 = Color32::from_rgba_premultiplied(40, 34, 0, 40);
 
+/// Drawing primitives `render_game` needs, so non-egui backends (or tests) can draw a frame
+pub trait Renderer {
+    /// Fill `rect` with corners rounded by `rounding`
+    fn fill_cell(&mut self, rect: Rect, rounding: f32, color: Color32);
+    fn line(&mut self, a: Pos2, b: Pos2, stroke: Stroke);
+    /// Draw `text` with its top-left corner at `pos`
+    fn text(&mut self, pos: Pos2, text: &str, size: f32, color: Color32);
+}
+
+/// egui adapter: draws straight onto the painter's layer
+impl Renderer for Painter {
+    fn fill_cell(&mut self, rect: Rect, rounding: f32, color: Color32) {
+        self.rect_filled(rect, rounding, color);
+    }
+
+    fn line(&mut self, a: Pos2, b: Pos2, stroke: Stroke) {
+        self.line_segment([a, b], stroke);
+    }
+
+    fn text(&mut self, pos: Pos2, text: &str, size: f32, color: Color32) {
+        Painter::text(self, pos, egui::Align2::LEFT_TOP, text, egui::FontId::proportional(size), color);
+    }
+}
+
+/// Ticks the edge a wrap went through stays highlighted
+#[cfg(feature = "wrap_walls")]
+const WRAP_FLASH_TICKS: u64 = 3;
+
+/// Special food pulse rate in radians per second
 #[cfg(feature = "multiple_foods")]
-const NORMAL_FOOD_COLOR: Color32 = Color32::from_rgb(200, 0, 0);
-#[cfg(feature = "multiple_foods")]
-const GOLDEN_FOOD_COLOR: Color32 = Color32::from_rgb(255, 215, 0);
-#[cfg(feature = "multiple_foods")]
-const SPECIAL_FOOD_COLOR: Color32 = Color32::from_rgb(255, 0, 255);
+const PULSE_SPEED: f64 = 4.0;
 
-/// Render the entire game state
-pub fn render_game(painter: &Painter, rect: Rect, game_state: &GameState) {
-    let (cell_size, grid_rect) = calculate_grid_layout(rect, game_state.grid);
+/// Colors used to draw a frame
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Theme {
+    pub background: Color32,
+    pub grid: Color32,
+    pub snake: Color32,
+    pub head: Color32,
+    /// Last segment color in `ColorMode::Gradient`
+    pub tail: Color32,
+    pub food: Color32,
+    #[cfg(feature = "multiple_foods")]
+    pub golden_food: Color32,
+    #[cfg(feature = "multiple_foods")]
+    pub special_food: Color32,
+    pub text: Color32,
+    pub game_over: Color32,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        palette_colors(Palette::Default)
+    }
+}
+
+/// Theme colors for a palette
+pub fn palette_colors(palette: Palette) -> Theme {
+    match palette {
+        Palette::Default => Theme {
+            background: Color32::from_rgb(20, 20, 20),
+            grid: Color32::from_rgb(40, 40, 40),
+            snake: Color32::from_rgb(0, 200, 0),
+            head: Color32::from_rgb(0, 255, 0),
+            tail: Color32::from_rgb(0, 80, 0),
+            food: Color32::from_rgb(200, 0, 0),
+            #[cfg(feature = "multiple_foods")]
+            golden_food: Color32::from_rgb(255, 215, 0),
+            #[cfg(feature = "multiple_foods")]
+            special_food: Color32::from_rgb(255, 0, 255),
+            text: Color32::WHITE,
+            game_over: Color32::from_rgb(255, 0, 0),
+        },
+        // Okabe-Ito colors: blue snake vs orange food, no red/green pairing
+        Palette::Deuteranopia => Theme {
+            background: Color32::from_rgb(20, 20, 20),
+            grid: Color32::from_rgb(45, 45, 45),
+            snake: Color32::from_rgb(0, 114, 178),
+            head: Color32::from_rgb(86, 180, 233),
+            tail: Color32::from_rgb(0, 50, 90),
+            food: Color32::from_rgb(230, 159, 0),
+            #[cfg(feature = "multiple_foods")]
+            golden_food: Color32::from_rgb(240, 228, 66),
+            #[cfg(feature = "multiple_foods")]
+            special_food: Color32::from_rgb(204, 121, 167),
+            text: Color32::WHITE,
+            game_over: Color32::from_rgb(230, 159, 0),
+        },
+        Palette::HighContrast => Theme {
+            background: Color32::BLACK,
+            grid: Color32::from_rgb(90, 90, 90),
+            snake: Color32::WHITE,
+            head: Color32::from_rgb(255, 255, 0),
+            tail: Color32::from_rgb(140, 140, 140),
+            food: Color32::from_rgb(255, 0, 255),
+            #[cfg(feature = "multiple_foods")]
+            golden_food: Color32::from_rgb(255, 170, 0),
+            #[cfg(feature = "multiple_foods")]
+            special_food: Color32::from_rgb(0, 255, 255),
+            text: Color32::WHITE,
+            game_over: Color32::from_rgb(255, 255, 0),
+        },
+    }
+}
+
+/// How snake body segments are colored
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorMode {
+    /// Head in the theme's head color, every other segment in its snake color
+    #[default]
+    Solid,
+    /// Fade from the head color at the head to the tail color at the last segment
+    Gradient,
+}
+
+/// Which items the HUD shows; the default is just the score
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HudConfig {
+    pub show_score: bool,
+    pub show_length: bool,
+    /// Best score to display next to the current one
+    pub show_high_score: Option<u32>,
+    /// Show the round's seed, when the game knows it
+    pub show_seed: bool,
+}
+
+impl Default for HudConfig {
+    fn default() -> Self {
+        Self {
+            show_score: true,
+            show_length: false,
+            show_high_score: None,
+            show_seed: false,
+        }
+    }
+}
+
+/// Cell shapes: gap around each snake/food cell and corner rounding
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RenderStyle {
+    /// Space left empty on every side of a drawn cell
+    pub cell_margin: f32,
+    pub snake_rounding: f32,
+    pub food_rounding: f32,
+}
+
+impl Default for RenderStyle {
+    fn default() -> Self {
+        Self {
+            cell_margin: 1.0,
+            snake_rounding: 2.0,
+            food_rounding: 3.0,
+        }
+    }
+}
+
+impl RenderStyle {
+    /// Part of `cell_rect` that gets filled, inset by `cell_margin`
+    pub fn inner_rect(&self, cell_rect: Rect) -> Rect {
+        cell_rect.shrink(self.cell_margin)
+    }
+}
+
+/// Presentation-only options for `render_game`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RenderOptions {
+    pub color_mode: ColorMode,
+    pub theme: Theme,
+    /// Label every cell with its coordinates (for debugging levels)
+    pub debug_overlay: bool,
+    pub hud: HudConfig,
+    /// Follow the head, drawing only a window of this many cells (columns, rows);
+    /// `None` draws the whole grid
+    pub camera: Option<(i32, i32)>,
+    pub style: RenderStyle,
+}
+
+/// Top-left and bottom-right cells (inclusive) of a `viewport_cells` window centered on
+/// `head`, shifted to stay inside the grid and shrunk to the grid when it is smaller
+pub fn visible_range(head: Position, viewport_cells: (i32, i32), grid: GridSize) -> (Position, Position) {
+    let axis = |head: i32, cells: i32, size: i32| {
+        let cells = cells.clamp(1, size.max(1));
+        let min = (head - cells / 2).clamp(0, (size - cells).max(0));
+        (min, min + cells - 1)
+    };
+    let (min_x, max_x) = axis(head.x, viewport_cells.0, grid.w);
+    let (min_y, max_y) = axis(head.y, viewport_cells.1, grid.h);
+    (Position { x: min_x, y: min_y }, Position { x: max_x, y: max_y })
+}
+
+/// Cells currently on screen
+#[derive(Clone, Copy)]
+struct View {
+    min: Position,
+    max: Position,
+}
+
+impl View {
+    fn contains(&self, pos: Position) -> bool {
+        (self.min.x..=self.max.x).contains(&pos.x) && (self.min.y..=self.max.y).contains(&pos.y)
+    }
+
+    fn size(&self) -> GridSize {
+        GridSize { w: self.max.x - self.min.x + 1, h: self.max.y - self.min.y + 1 }
+    }
+}
+
+/// Render the entire game state; `time` (seconds) drives animations such as food pulsing
+pub fn render_game<D: Renderer>(
+    painter: &mut D,
+    rect: Rect,
+    game_state: &GameState,
+    options: &RenderOptions,
+    #[cfg_attr(not(feature = "multiple_foods"), allow(unused_variables))] time: f64,
+) {
+    let theme = &options.theme;
+    let style = &options.style;
+    let grid = game_state.grid;
+    let view = match options.camera {
+        Some(cells) => {
+            let head = game_state.snake.body.front().copied().unwrap_or(grid.center());
+            let (min, max) = visible_range(head, cells, grid);
+            View { min, max }
+        }
+        None => View { min: Position { x: 0, y: 0 }, max: Position { x: grid.w - 1, y: grid.h - 1 } },
+    };
+    let (cell_size, view_rect) = calculate_grid_layout(rect, view.size());
+    // Cells are placed on the full grid, shifted so the camera's corner lands on the view's
+    let grid_rect = view_rect.translate(-egui::vec2(view.min.x as f32, view.min.y as f32) * cell_size);
 
     // Draw background
-    painter.rect_filled(rect, 0.0, Color32::from_rgb(20, 20, 20));
+    painter.fill_cell(rect, 0.0, theme.background);
 
     // Draw grid
-    draw_grid(painter, &grid_rect, game_state.grid, cell_size);
+    draw_grid(painter, &view_rect, view.size(), cell_size, theme);
+
+    // Draw the double-points region under the food
+    if let Some((a, b)) = game_state.bonus_zone {
+        draw_bonus_zone(painter, &grid_rect, (a, b), view, cell_size);
+    }
 
     // Draw foods
     #[cfg(not(feature = "multiple_foods"))]
-    draw_food(painter, &grid_rect, game_state.food, cell_size);
-    
+    if view.contains(game_state.food) {
+        draw_food(painter, &grid_rect, game_state.food, cell_size, theme, style);
+    }
+
     #[cfg(feature = "multiple_foods")]
-    draw_foods(painter, &grid_rect, &game_state.foods, cell_size);
+    {
+        let foods: Vec<Food> = game_state.foods.iter().filter(|f| view.contains(f.position)).cloned().collect();
+        draw_foods(painter, &grid_rect, &foods, cell_size, theme, style, time);
+    }
+
+    // Draw roaming prey
+    if let Some(prey) = game_state.roaming_food.filter(|&p| view.contains(p)) {
+        draw_roaming_food(painter, &grid_rect, prey, cell_size, theme);
+    }
+
+    // Draw the combo trail of recently eaten food
+    draw_eat_trail(painter, &grid_rect, &game_state.recent_eats, view, cell_size, theme);
 
     // Draw snake
-    draw_snake(painter, &grid_rect, &game_state.snake, cell_size);
+    draw_snake(painter, &grid_rect, &game_state.snake, cell_size, options, view);
+
+    // Mark where the fatal move was headed; a wall hit lands just outside the view
+    if let Some(cell) = game_state.death_cell {
+        let around = View {
+            min: Position { x: view.min.x - 1, y: view.min.y - 1 },
+            max: Position { x: view.max.x + 1, y: view.max.y + 1 },
+        };
+        if around.contains(cell) {
+            let cell_rect = cell_rect_for_position(&grid_rect, cell, cell_size);
+            painter.fill_cell(style.inner_rect(cell_rect), style.snake_rounding, theme.game_over);
+        }
+    }
+
+    // Flash the edge the snake just wrapped through
+    #[cfg(feature = "wrap_walls")]
+    if let Some(dir) = wrap_flash_edge(game_state) {
+        draw_wrap_flash(painter, &view_rect, dir, theme);
+    }
+
+    // Draw coordinate labels
+    let mut labels = debug_labels(game_state, options);
+    labels.retain(|(pos, _, _)| view.contains(*pos));
+    draw_debug_overlay(painter, &grid_rect, cell_size, &labels, theme);
 
     // Draw HUD
-    draw_hud(painter, rect, game_state);
+    draw_hud(painter, rect, game_state, &options.hud, theme);
 }
 
 /// Calculate cell size and grid rectangle from available space
@@ -63,92 +325,290 @@ fn calculate_grid_layout(available_rect: Rect, grid_size: GridSize) -> (f32, Rec
 }
 
 /// Draw the grid outline
-fn draw_grid(painter: &Painter, grid_rect: &Rect, grid_size: GridSize, cell_size: f32) {
+fn draw_grid<D: Renderer>(painter: &mut D, grid_rect: &Rect, grid_size: GridSize, cell_size: f32, theme: &Theme) {
     // Draw grid lines
     for x in 0..=grid_size.w {
         let x_pos = grid_rect.min.x + x as f32 * cell_size;
-        painter.line_segment(
-            [
-                egui::pos2(x_pos, grid_rect.min.y),
-                egui::pos2(x_pos, grid_rect.max.y),
-            ],
-            Stroke::new(1.0, GRID_COLOR),
+        painter.line(
+            egui::pos2(x_pos, grid_rect.min.y),
+            egui::pos2(x_pos, grid_rect.max.y),
+            Stroke::new(1.0, theme.grid),
         );
     }
 
     for y in 0..=grid_size.h {
         let y_pos = grid_rect.min.y + y as f32 * cell_size;
-        painter.line_segment(
-            [
-                egui::pos2(grid_rect.min.x, y_pos),
-                egui::pos2(grid_rect.max.x, y_pos),
-            ],
-            Stroke::new(1.0, GRID_COLOR),
+        painter.line(
+            egui::pos2(grid_rect.min.x, y_pos),
+            egui::pos2(grid_rect.max.x, y_pos),
+            Stroke::new(1.0, theme.grid),
         );
     }
 }
 
+/// Tint the part of the bonus zone (corners `a`, `b`) that is inside the view
+fn draw_bonus_zone<D: Renderer>(painter: &mut D, grid_rect: &Rect, (a, b): (Position, Position), view: View, cell_size: f32) {
+    let min = Position { x: a.x.min(b.x).max(view.min.x), y: a.y.min(b.y).max(view.min.y) };
+    let max = Position { x: a.x.max(b.x).min(view.max.x), y: a.y.max(b.y).min(view.max.y) };
+    if min.x > max.x || min.y > max.y {
+        return;
+    }
+    let zone = cell_rect_for_position(grid_rect, min, cell_size).union(cell_rect_for_position(grid_rect, max, cell_size));
+    painter.fill_cell(zone, 0.0, BONUS_ZONE_TINT);
+}
+
+/// Small markers on recently eaten cells, fading from the newest to the oldest
+fn draw_eat_trail<D: Renderer>(
+    painter: &mut D,
+    grid_rect: &Rect,
+    recent_eats: &std::collections::VecDeque<Position>,
+    view: View,
+    cell_size: f32,
+    theme: &Theme,
+) {
+    let len = recent_eats.len();
+    for (i, &pos) in recent_eats.iter().enumerate() {
+        if !view.contains(pos) {
+            continue;
+        }
+        let cell_rect = cell_rect_for_position(grid_rect, pos, cell_size);
+        let marker = Rect::from_center_size(cell_rect.center(), egui::vec2(cell_size, cell_size) * 0.3);
+        painter.fill_cell(marker, cell_size * 0.15, trail_color(theme.food, i, len));
+    }
+}
+
+/// Food color faded for trail entry `index` of `len` (oldest first): the newest is
+/// half-opaque and each older one fainter
+fn trail_color(base: Color32, index: usize, len: usize) -> Color32 {
+    let alpha = 128 * (index + 1) / len.max(1);
+    Color32::from_rgba_unmultiplied(base.r(), base.g(), base.b(), alpha as u8)
+}
+
+/// Edge (named by the direction of travel) to highlight after a recent wrap
+#[cfg(feature = "wrap_walls")]
+fn wrap_flash_edge(game_state: &GameState) -> Option<Direction> {
+    game_state
+        .last_wrap
+        .filter(|&(_, at)| game_state.tick.saturating_sub(at) < WRAP_FLASH_TICKS)
+        .map(|(dir, _)| dir)
+}
+
+/// Highlight the side of `rect` the snake left through when moving in `dir`
+#[cfg(feature = "wrap_walls")]
+fn draw_wrap_flash<D: Renderer>(painter: &mut D, rect: &Rect, dir: Direction, theme: &Theme) {
+    let (a, b) = match dir {
+        Direction::Up => (rect.left_top(), rect.right_top()),
+        Direction::Down => (rect.left_bottom(), rect.right_bottom()),
+        Direction::Left => (rect.left_top(), rect.left_bottom()),
+        Direction::Right => (rect.right_top(), rect.right_bottom()),
+    };
+    painter.line(a, b, Stroke::new(4.0, theme.head));
+}
+
 /// Draw the food
 #[cfg(not(feature = "multiple_foods"))]
-fn draw_food(painter: &Painter, grid_rect: &Rect, food: Position, cell_size: f32) {
+fn draw_food<D: Renderer>(
+    painter: &mut D,
+    grid_rect: &Rect,
+    food: Position,
+    cell_size: f32,
+    theme: &Theme,
+    style: &RenderStyle,
+) {
     let cell_rect = cell_rect_for_position(grid_rect, food, cell_size);
-    painter.rect_filled(cell_rect.shrink(CELL_MARGIN), 3.0, FOOD_COLOR);
+    painter.fill_cell(style.inner_rect(cell_rect), style.food_rounding, theme.food);
 }
 
-/// Draw all foods with different colors based on type
+/// Draw all foods with different colors based on type; special food pulses over `time`
 #[cfg(feature = "multiple_foods")]
-fn draw_foods(painter: &Painter, grid_rect: &Rect, foods: &[Food], cell_size: f32) {
+fn draw_foods<D: Renderer>(
+    painter: &mut D,
+    grid_rect: &Rect,
+    foods: &[Food],
+    cell_size: f32,
+    theme: &Theme,
+    style: &RenderStyle,
+    time: f64,
+) {
+    let phase = (time * PULSE_SPEED).rem_euclid(std::f64::consts::TAU) as f32;
     for food in foods {
         let cell_rect = cell_rect_for_position(grid_rect, food.position, cell_size);
         let color = match food.food_type {
-            FoodType::Normal => NORMAL_FOOD_COLOR,
-            FoodType::Golden => GOLDEN_FOOD_COLOR,
-            FoodType::Special => SPECIAL_FOOD_COLOR,
+            FoodType::Normal => theme.food,
+            FoodType::Golden => theme.golden_food,
+            FoodType::Special => pulse_color(theme.special_food, phase),
         };
         // Special food gets a slightly larger size to make it more noticeable
         let margin = if food.food_type == FoodType::Special {
-            CELL_MARGIN * 0.5
+            style.cell_margin * 0.5
         } else {
-            CELL_MARGIN
+            style.cell_margin
         };
-        painter.rect_filled(cell_rect.shrink(margin), 3.0, color);
+        painter.fill_cell(cell_rect.shrink(margin), style.food_rounding, color);
     }
 }
 
+/// Scale `base`'s brightness with a cosine wave: full at phase 0, half at phase pi
+#[cfg(feature = "multiple_foods")]
+fn pulse_color(base: Color32, phase: f32) -> Color32 {
+    let factor = 0.75 + 0.25 * phase.cos();
+    let scale = |c: u8| (c as f32 * factor).round().clamp(0.0, 255.0) as u8;
+    Color32::from_rgba_unmultiplied(scale(base.r()), scale(base.g()), scale(base.b()), base.a())
+}
+
+/// Draw the roaming food as a disc so it stands apart from regular food
+fn draw_roaming_food<D: Renderer>(painter: &mut D, grid_rect: &Rect, prey: Position, cell_size: f32, theme: &Theme) {
+    let cell_rect = cell_rect_for_position(grid_rect, prey, cell_size);
+    // A square rounded by half its side is a disc
+    let radius = cell_size * 0.4;
+    let disc = Rect::from_center_size(cell_rect.center(), egui::vec2(radius, radius) * 2.0);
+    painter.fill_cell(disc, radius, theme.food);
+}
+
 /// Draw the snake
-fn draw_snake(painter: &Painter, grid_rect: &Rect, snake: &crate::state::Snake, cell_size: f32) {
+fn draw_snake<D: Renderer>(
+    painter: &mut D,
+    grid_rect: &Rect,
+    snake: &crate::state::Snake,
+    cell_size: f32,
+    options: &RenderOptions,
+    view: View,
+) {
+    let theme = &options.theme;
+    let len = snake.body.len();
     for (i, pos) in snake.body.iter().enumerate() {
+        if !view.contains(*pos) {
+            continue;
+        }
+        let cell_rect = cell_rect_for_position(grid_rect, *pos, cell_size);
+        let color = match options.color_mode {
+            ColorMode::Solid if i == 0 => theme.head,
+            ColorMode::Solid => theme.snake,
+            ColorMode::Gradient => segment_color(i, len, theme.head, theme.tail),
+        };
+        painter.fill_cell(options.style.inner_rect(cell_rect), options.style.snake_rounding, color);
+    }
+}
+
+/// Interpolate a segment's color between `head` and `tail` by its index fraction
+fn segment_color(index: usize, len: usize, head: Color32, tail: Color32) -> Color32 {
+    if len <= 1 {
+        return head;
+    }
+    let t = index.min(len - 1) as f32 / (len - 1) as f32;
+    let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    Color32::from_rgb(
+        lerp(head.r(), tail.r()),
+        lerp(head.g(), tail.g()),
+        lerp(head.b(), tail.b()),
+    )
+}
+
+/// Coordinate label for a cell, e.g. `"3,4"`
+fn cell_label(pos: Position) -> String {
+    format!("{},{}", pos.x, pos.y)
+}
+
+/// Labels the debug overlay draws, flagged when the cell holds the snake's head; empty
+/// unless `options.debug_overlay` is set
+fn debug_labels(game_state: &GameState, options: &RenderOptions) -> Vec<(Position, String, bool)> {
+    if !options.debug_overlay {
+        return Vec::new();
+    }
+    let head = game_state.snake.body.front().copied();
+    (0..game_state.grid.h)
+        .flat_map(|y| (0..game_state.grid.w).map(move |x| Position { x, y }))
+        .map(|pos| (pos, cell_label(pos), Some(pos) == head))
+        .collect()
+}
+
+/// Draw coordinate labels in the top-left corner of each cell, highlighting the head cell
+fn draw_debug_overlay<D: Renderer>(
+    painter: &mut D,
+    grid_rect: &Rect,
+    cell_size: f32,
+    labels: &[(Position, String, bool)],
+    theme: &Theme,
+) {
+    let size = hud_font_size().min(cell_size * 0.3);
+
+    for (pos, label, is_head) in labels {
         let cell_rect = cell_rect_for_position(grid_rect, *pos, cell_size);
-        let color = if i == 0 { HEAD_COLOR } else { SNAKE_COLOR };
-        painter.rect_filled(cell_rect.shrink(CELL_MARGIN), 2.0, color);
+        let color = if *is_head {
+            outline_rect(painter, cell_rect, Stroke::new(2.0, theme.head));
+            theme.head
+        } else {
+            theme.text
+        };
+        painter.text(cell_rect.min + egui::vec2(1.0, 1.0), label, size, color);
+    }
+}
+
+/// Trace the four edges of `rect`
+fn outline_rect<D: Renderer>(painter: &mut D, rect: Rect, stroke: Stroke) {
+    let corners = [rect.left_top(), rect.right_top(), rect.right_bottom(), rect.left_bottom()];
+    for i in 0..corners.len() {
+        painter.line(corners[i], corners[(i + 1) % corners.len()], stroke);
     }
 }
 
-/// Draw the HUD (score, game over message)
-fn draw_hud(painter: &Painter, rect: Rect, game_state: &GameState) {
+/// Size of egui's body text, used for the HUD
+fn hud_font_size() -> f32 {
+    TextStyle::Body.resolve(&Style::default()).size
+}
+
+/// HUD text items in display order, e.g. `["Score: 3", "Best: 10"]`
+fn hud_lines(game_state: &GameState, hud: &HudConfig) -> Vec<String> {
+    let mut lines = Vec::new();
+    if hud.show_score {
+        lines.push(format!("Score: {}", game_state.display_score()));
+    }
+    if let Some(best) = hud.show_high_score {
+        lines.push(format!("Best: {}", best));
+    }
+    if hud.show_length {
+        lines.push(format!("Length: {}", game_state.snake.body.len()));
+    }
+    if let (true, Some(seed)) = (hud.show_seed, game_state.seed) {
+        lines.push(format!("Seed: {}", seed));
+    }
+    lines
+}
+
+/// Game over banner naming the cause when known
+fn game_over_message(reason: Option<GameOverReason>) -> &'static str {
+    match reason {
+        Some(GameOverReason::Wall) => "GAME OVER \u{2014} Hit Wall",
+        Some(GameOverReason::SelfCollision) => "GAME OVER \u{2014} Self Collision",
+        None => "GAME OVER",
+    }
+}
+
+/// Draw the HUD (configured stats, countdown, game over message)
+fn draw_hud<D: Renderer>(painter: &mut D, rect: Rect, game_state: &GameState, hud: &HudConfig, theme: &Theme) {
     let hud_y = rect.max.y - 80.0;
-    let font = TextStyle::Body.resolve(&Style::default());
-
-    // Score
-    let score_text = format!("Score: {}", game_state.score);
-    painter.text(
-        egui::pos2(rect.min.x + 10.0, hud_y),
-        egui::Align2::LEFT_TOP,
-        score_text,
-        font.clone(),
-        Color32::WHITE,
-    );
+    let size = hud_font_size();
+
+    // Stats on one row
+    let stats = hud_lines(game_state, hud).join("   ");
+    painter.text(egui::pos2(rect.min.x + 10.0, hud_y), &stats, size, theme.text);
+
+    if let RunState::Countdown(n) = game_state.run_state {
+        painter.text(egui::pos2(rect.min.x + 10.0, hud_y + 25.0), &n.to_string(), size, theme.text);
+    }
 
     // Game over message
     if game_state.is_over() {
-        let game_over_text = "GAME OVER";
-        painter.text(
-            egui::pos2(rect.min.x + 10.0, hud_y + 25.0),
-            egui::Align2::LEFT_TOP,
-            game_over_text,
-            font,
-            Color32::from_rgb(255, 0, 0),
-        );
+        let game_over_text = game_over_message(game_state.game_over_reason());
+        painter.text(egui::pos2(rect.min.x + 10.0, hud_y + 25.0), game_over_text, size, theme.game_over);
+    }
+    if game_state.is_won() {
+        let win_text = match game_state.win_reason() {
+            Some(WinReason::TimeSurvived) => "YOU WIN \u{2014} Survived",
+            Some(WinReason::QuotaCleared) => "YOU WIN \u{2014} All Food Eaten",
+            None => "YOU WIN",
+        };
+        painter.text(egui::pos2(rect.min.x + 10.0, hud_y + 25.0), win_text, size, theme.text);
     }
 }
 
@@ -158,3 +618,313 @@ fn cell_rect_for_position(grid_rect: &Rect, pos: Position, cell_size: f32) -> Re
     let min_y = grid_rect.min.y + pos.y as f32 * cell_size;
     Rect::from_min_size(egui::pos2(min_x, min_y), egui::vec2(cell_size, cell_size))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Renderer that records filled cells instead of drawing
+    #[derive(Default)]
+    struct RecordingRenderer {
+        fills: Vec<(Rect, Color32)>,
+        lines: usize,
+        texts: Vec<String>,
+    }
+
+    impl Renderer for RecordingRenderer {
+        fn fill_cell(&mut self, rect: Rect, _rounding: f32, color: Color32) {
+            self.fills.push((rect, color));
+        }
+
+        fn line(&mut self, _a: Pos2, _b: Pos2, _stroke: Stroke) {
+            self.lines += 1;
+        }
+
+        fn text(&mut self, _pos: Pos2, text: &str, _size: f32, _color: Color32) {
+            self.texts.push(text.to_string());
+        }
+    }
+
+    #[test]
+    fn test_recording_renderer_sees_head_cell_in_head_color() {
+        let game_state = GameState::new(GridSize { w: 10, h: 10 }, crate::rng::Seeded::new(1));
+        let options = RenderOptions::default();
+        let rect = Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(420.0, 500.0));
+        let mut recorder = RecordingRenderer::default();
+
+        render_game(&mut recorder, rect, &game_state, &options, 0.0);
+
+        let (cell_size, grid_rect) = calculate_grid_layout(rect, game_state.grid);
+        let head_rect = cell_rect_for_position(&grid_rect, game_state.snake.head(), cell_size);
+        assert!(recorder.fills.contains(&(head_rect.shrink(1.0), options.theme.head)));
+        assert_eq!(recorder.lines, 22, "11 vertical and 11 horizontal grid lines");
+        assert_eq!(recorder.texts, vec!["Score: 0"]);
+    }
+
+    #[test]
+    fn test_hud_shows_countdown_ticks_left() {
+        let mut game_state = GameState::new(GridSize { w: 10, h: 10 }, crate::rng::Seeded::new(1));
+        game_state.start_countdown(3);
+        let rect = Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(420.0, 500.0));
+        let mut recorder = RecordingRenderer::default();
+
+        render_game(&mut recorder, rect, &game_state, &RenderOptions::default(), 0.0);
+
+        assert_eq!(recorder.texts, vec!["Score: 0", "3"]);
+    }
+
+    #[test]
+    fn test_custom_style_margin_shrinks_drawn_cells() {
+        let game_state = GameState::new(GridSize { w: 10, h: 10 }, crate::rng::Seeded::new(1));
+        let style = RenderStyle { cell_margin: 4.0, snake_rounding: 0.0, food_rounding: 6.0 };
+        let options = RenderOptions { style, ..Default::default() };
+        let rect = Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(420.0, 500.0));
+        let mut recorder = RecordingRenderer::default();
+
+        render_game(&mut recorder, rect, &game_state, &options, 0.0);
+
+        let (cell_size, grid_rect) = calculate_grid_layout(rect, game_state.grid);
+        let head_rect = cell_rect_for_position(&grid_rect, game_state.snake.head(), cell_size);
+        let inner = style.inner_rect(head_rect);
+        assert_eq!(inner.width(), cell_size - 8.0);
+        assert_eq!(inner.min, head_rect.min + egui::vec2(4.0, 4.0));
+        assert!(recorder.fills.contains(&(inner, options.theme.head)));
+    }
+
+    #[test]
+    fn test_visible_range_centered_on_head() {
+        let grid = GridSize { w: 100, h: 100 };
+        let (min, max) = visible_range(Position { x: 50, y: 40 }, (20, 10), grid);
+        assert_eq!(min, Position { x: 40, y: 35 });
+        assert_eq!(max, Position { x: 59, y: 44 });
+    }
+
+    #[test]
+    fn test_visible_range_clamped_at_edges() {
+        let grid = GridSize { w: 100, h: 100 };
+        let (min, max) = visible_range(Position { x: 2, y: 98 }, (20, 10), grid);
+        assert_eq!(min, Position { x: 0, y: 90 });
+        assert_eq!(max, Position { x: 19, y: 99 });
+
+        // A viewport larger than the grid shows the whole grid
+        let small = GridSize { w: 8, h: 6 };
+        let (min, max) = visible_range(Position { x: 4, y: 3 }, (20, 10), small);
+        assert_eq!(min, Position { x: 0, y: 0 });
+        assert_eq!(max, Position { x: 7, y: 5 });
+    }
+
+    #[test]
+    fn test_visible_range_always_contains_head() {
+        let grid = GridSize { w: 37, h: 23 };
+        for x in 0..grid.w {
+            for y in 0..grid.h {
+                for cells in [(1, 1), (5, 4), (10, 10), (50, 50)] {
+                    let head = Position { x, y };
+                    let (min, max) = visible_range(head, cells, grid);
+                    let view = View { min, max };
+                    assert!(view.contains(head), "{:?} outside {:?}..{:?}", head, min, max);
+                    assert!(min.x >= 0 && min.y >= 0 && max.x < grid.w && max.y < grid.h);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_camera_skips_cells_outside_the_view() {
+        let mut game_state = GameState::new(GridSize { w: 100, h: 100 }, crate::rng::Seeded::new(1));
+        game_state.snake.body = [Position { x: 50, y: 50 }, Position { x: 10, y: 10 }].into_iter().collect();
+        let options = RenderOptions { camera: Some((9, 9)), ..Default::default() };
+        let mut recorder = RecordingRenderer::default();
+        render_game(&mut recorder, Rect::from_min_size(Pos2::ZERO, egui::vec2(400.0, 500.0)), &game_state, &options, 0.0);
+
+        let snake_cells = recorder.fills.iter().filter(|(_, c)| *c == options.theme.head || *c == options.theme.snake).count();
+        assert_eq!(snake_cells, 1, "only the head is inside the camera window");
+    }
+
+    #[test]
+    fn test_bonus_zone_tint_covers_zone_cells() {
+        let mut game_state = GameState::new(GridSize { w: 10, h: 10 }, crate::rng::Seeded::new(1));
+        game_state.bonus_zone = Some((Position { x: 6, y: 4 }, Position { x: 2, y: 1 }));
+        let rect = Rect::from_min_size(Pos2::ZERO, egui::vec2(400.0, 500.0));
+        let mut recorder = RecordingRenderer::default();
+        render_game(&mut recorder, rect, &game_state, &RenderOptions::default(), 0.0);
+
+        let (cell_size, grid_rect) = calculate_grid_layout(rect, game_state.grid);
+        let expected = cell_rect_for_position(&grid_rect, Position { x: 2, y: 1 }, cell_size)
+            .union(cell_rect_for_position(&grid_rect, Position { x: 6, y: 4 }, cell_size));
+        assert!(recorder.fills.contains(&(expected, BONUS_ZONE_TINT)));
+    }
+
+    #[test]
+    fn test_trail_color_fades_with_age() {
+        let base = Color32::from_rgb(200, 40, 40);
+        let alphas: Vec<u8> = (0..4).map(|i| trail_color(base, i, 4).a()).collect();
+        assert!(alphas.windows(2).all(|w| w[0] < w[1]), "{:?}", alphas);
+        assert_eq!(alphas[3], 128);
+    }
+
+    #[cfg(feature = "wrap_walls")]
+    #[test]
+    fn test_wrap_flash_expires() {
+        let mut game_state = GameState::new(GridSize { w: 10, h: 10 }, crate::rng::Seeded::new(1));
+        assert_eq!(wrap_flash_edge(&game_state), None);
+
+        game_state.tick = 5;
+        game_state.last_wrap = Some((Direction::Left, 5));
+        assert_eq!(wrap_flash_edge(&game_state), Some(Direction::Left));
+        game_state.tick = 5 + WRAP_FLASH_TICKS - 1;
+        assert_eq!(wrap_flash_edge(&game_state), Some(Direction::Left));
+        game_state.tick = 5 + WRAP_FLASH_TICKS;
+        assert_eq!(wrap_flash_edge(&game_state), None);
+    }
+
+    #[test]
+    fn test_cell_label_format() {
+        assert_eq!(cell_label(Position { x: 3, y: 4 }), "3,4");
+    }
+
+    #[test]
+    fn test_debug_labels_gated_by_overlay_flag() {
+        let game_state = GameState::new(GridSize { w: 4, h: 3 }, crate::rng::Seeded::new(1));
+        let mut options = RenderOptions::default();
+        assert!(debug_labels(&game_state, &options).is_empty());
+
+        options.debug_overlay = true;
+        let labels = debug_labels(&game_state, &options);
+        assert_eq!(labels.len(), 12);
+        let heads: Vec<_> = labels.iter().filter(|(_, _, is_head)| *is_head).collect();
+        assert_eq!(heads.len(), 1);
+        assert_eq!(heads[0].0, game_state.snake.head());
+        assert_eq!(heads[0].1, "2,1");
+    }
+
+    #[test]
+    fn test_hud_lines_default_shows_score_only() {
+        let mut game_state = GameState::new(GridSize { w: 10, h: 10 }, crate::rng::Seeded::new(1));
+        game_state.set_score(7);
+        assert_eq!(hud_lines(&game_state, &HudConfig::default()), vec!["Score: 7"]);
+    }
+
+    #[test]
+    fn test_hud_lines_with_length_and_best() {
+        let mut game_state = GameState::new(GridSize { w: 10, h: 10 }, crate::rng::Seeded::new(1));
+        game_state.set_score(3);
+        game_state.snake.body.push_back(Position { x: 4, y: 5 });
+        let hud = HudConfig { show_score: true, show_length: true, show_high_score: Some(12), show_seed: false };
+        assert_eq!(hud_lines(&game_state, &hud), vec!["Score: 3", "Best: 12", "Length: 2"]);
+
+        let hidden = HudConfig { show_score: false, show_length: false, show_high_score: None, show_seed: false };
+        assert!(hud_lines(&game_state, &hidden).is_empty());
+    }
+
+    #[test]
+    fn test_hud_lines_show_known_seed() {
+        let hud = HudConfig { show_seed: true, ..Default::default() };
+        let unseeded = GameState::new(GridSize { w: 10, h: 10 }, crate::rng::Seeded::new(1));
+        assert_eq!(hud_lines(&unseeded, &hud), vec!["Score: 0"]);
+
+        let seeded = GameState::from_seed(GridSize { w: 10, h: 10 }, 1234);
+        assert_eq!(hud_lines(&seeded, &hud), vec!["Score: 0", "Seed: 1234"]);
+    }
+
+    #[cfg(feature = "multiple_foods")]
+    #[test]
+    fn test_pulse_color_full_at_zero_and_dimmed_at_pi() {
+        let base = Color32::from_rgb(255, 128, 10);
+        assert_eq!(pulse_color(base, 0.0), base);
+
+        let dimmed = pulse_color(base, std::f32::consts::PI);
+        assert_eq!(dimmed, Color32::from_rgb(128, 64, 5));
+    }
+
+    #[cfg(feature = "multiple_foods")]
+    #[test]
+    fn test_pulse_color_channels_stay_in_range() {
+        let base = Color32::from_rgb(255, 255, 255);
+        for step in 0..64 {
+            let c = pulse_color(base, step as f32 * 0.1);
+            assert!(c.r() >= 127 && c.g() == c.r() && c.b() == c.r());
+        }
+    }
+
+    #[test]
+    fn test_game_over_message_names_reason() {
+        assert_eq!(game_over_message(Some(GameOverReason::Wall)), "GAME OVER \u{2014} Hit Wall");
+        assert_eq!(
+            game_over_message(Some(GameOverReason::SelfCollision)),
+            "GAME OVER \u{2014} Self Collision"
+        );
+        assert_eq!(game_over_message(None), "GAME OVER");
+    }
+
+    #[test]
+    fn test_segment_color_endpoints() {
+        let head = Color32::from_rgb(0, 255, 0);
+        let tail = Color32::from_rgb(0, 80, 0);
+        assert_eq!(segment_color(0, 5, head, tail), head);
+        assert_eq!(segment_color(4, 5, head, tail), tail);
+    }
+
+    #[test]
+    fn test_segment_color_midpoint_is_average() {
+        let head = Color32::from_rgb(200, 100, 0);
+        let tail = Color32::from_rgb(0, 20, 50);
+        assert_eq!(
+            segment_color(2, 5, head, tail),
+            Color32::from_rgb(100, 60, 25)
+        );
+    }
+
+    /// Hue in degrees, or `None` for greys
+    fn hue(c: Color32) -> Option<f32> {
+        let (r, g, b) = (c.r() as f32, c.g() as f32, c.b() as f32);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+        if delta == 0.0 {
+            return None;
+        }
+        let h = if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        Some(h)
+    }
+
+    fn is_reddish(c: Color32) -> bool {
+        hue(c).is_some_and(|h| !(20.0..340.0).contains(&h))
+    }
+
+    fn is_greenish(c: Color32) -> bool {
+        hue(c).is_some_and(|h| (80.0..160.0).contains(&h))
+    }
+
+    #[test]
+    fn test_deuteranopia_palette_avoids_red_green_pairing() {
+        let theme = palette_colors(Palette::Deuteranopia);
+        for snake in [theme.snake, theme.head, theme.tail] {
+            assert!(!(is_greenish(snake) && is_reddish(theme.food)));
+            assert!(!(is_reddish(snake) && is_greenish(theme.food)));
+            // Snake and food hues sit well apart on the color wheel
+            let diff = (hue(snake).unwrap() - hue(theme.food).unwrap()).abs();
+            assert!(diff.min(360.0 - diff) >= 90.0, "hue separation {}", diff);
+        }
+    }
+
+    #[test]
+    fn test_default_palette_matches_default_theme() {
+        assert_eq!(Theme::default(), palette_colors(Palette::Default));
+        assert!(is_greenish(Theme::default().snake));
+        assert!(is_reddish(Theme::default().food));
+    }
+
+    #[test]
+    fn test_segment_color_single_segment_is_head() {
+        let head = Color32::from_rgb(0, 255, 0);
+        let tail = Color32::from_rgb(0, 80, 0);
+        assert_eq!(segment_color(0, 1, head, tail), head);
+    }
+}
@@ -1,18 +1,84 @@
 /// Trait for random number generators
 pub trait RngLike {
     fn next_u32(&mut self) -> u32;
+
+    /// Value in `0..n` (modulo bias is negligible for grid-sized `n`); `n` must be non-zero
+    fn next_range(&mut self, n: u32) -> u32 {
+        self.next_u32() % n
+    }
+
+    /// True with probability `p_percent`% (100 or more is always true, 0 always false)
+    fn next_bool(&mut self, p_percent: u32) -> bool {
+        self.next_range(100) < p_percent
+    }
+
+    /// Index into `weights`, chosen with probability proportional to its weight;
+    /// the weights must not all be zero
+    fn weighted_index(&mut self, weights: &[u32]) -> usize {
+        let total: u32 = weights.iter().sum();
+        let mut roll = self.next_range(total);
+        for (i, &w) in weights.iter().enumerate() {
+            if roll < w {
+                return i;
+            }
+            roll -= w;
+        }
+        unreachable!("roll is below the total weight")
+    }
+
+    /// Shuffle `slice` in place (Fisher-Yates), reproducibly for a given RNG state
+    fn shuffle<T>(&mut self, slice: &mut [T])
+    where
+        Self: Sized,
+    {
+        for i in (1..slice.len()).rev() {
+            let j = self.next_range(i as u32 + 1) as usize;
+            slice.swap(i, j);
+        }
+    }
+}
+
+impl<R: RngLike + ?Sized> RngLike for &mut R {
+    fn next_u32(&mut self) -> u32 {
+        (**self).next_u32()
+    }
+}
+
+/// Lets a `Box<dyn RngLike>` be chosen at runtime and passed anywhere an RNG is expected
+impl<R: RngLike + ?Sized> RngLike for Box<R> {
+    fn next_u32(&mut self) -> u32 {
+        (**self).next_u32()
+    }
 }
 
 /// A deterministic random number generator using a seeded algorithm
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Seeded(u64);
 
 impl Seeded {
     pub fn new(seed: u64) -> Self {
-        Self(seed)
+        // xorshift never leaves the all-zero state, so map seed 0 to a fixed odd constant
+        if seed == 0 {
+            Self(0x9E37_79B9_7F4A_7C15)
+        } else {
+            Self(seed)
+        }
+    }
+
+    /// `Seeded` from a shareable passphrase such as `"river-otter-42"` (see `seed_from_str`)
+    pub fn from_str_seed(s: &str) -> Self {
+        Self::new(seed_from_str(s))
     }
 }
 
+/// Stable 64-bit seed for a passphrase: the FNV-1a hash of its UTF-8 bytes, so the same
+/// string names the same board on every platform and build
+pub fn seed_from_str(s: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    s.bytes().fold(OFFSET_BASIS, |hash, byte| (hash ^ byte as u64).wrapping_mul(PRIME))
+}
+
 impl RngLike for Seeded {
     fn next_u32(&mut self) -> u32 {
         // Simple xorshift64* algorithm (fast, deterministic for tests)
@@ -24,3 +90,23 @@ impl RngLike for Seeded {
         (x.wrapping_mul(0x2545F4914F6CDD1D) >> 32) as u32
     }
 }
+
+/// RNG whose position in its stream can be captured and restored (replay checkpoints)
+pub trait RngSnapshot: RngLike {
+    /// Opaque state that reproduces the remaining sequence via `from_state`
+    fn state(&self) -> u64;
+    fn from_state(state: u64) -> Self
+    where
+        Self: Sized;
+}
+
+impl RngSnapshot for Seeded {
+    fn state(&self) -> u64 {
+        self.0
+    }
+
+    fn from_state(state: u64) -> Self {
+        // A zero state would get stuck, so it gets the same mapping as seed 0
+        Self::new(state)
+    }
+}
@@ -1,55 +1,95 @@
 use crate::{
     rng::RngLike,
-    state::{GameState, RunState, Snake},
+    state::{GameOverReason, GameState, RunState, WallBehavior},
     types::*,
 };
+#[cfg(not(feature = "multiple_foods"))]
+use crate::state::spawn_food;
 #[cfg(feature = "multiple_foods")]
-use crate::types::{Food, FoodType};
+use crate::{
+    state::spawn_food_with_type,
+    types::{Food, FoodType},
+};
+#[cfg(feature = "cluster_food")]
+use crate::state::{spawn_cluster_clear_of_head, CLUSTER_SIZE};
 
+/// Points for catching the roaming food
+pub const ROAMING_FOOD_POINTS: u32 = 3;
+
+/// Advance the game by one move
+///
+/// Expects a snake with at least one segment; a state edited into an empty body is
+/// ended (`RunState::Over`) rather than stepped.
 pub fn step<R: RngLike>(g: &mut GameState, rng: &mut R) {
-    if matches!(g.run_state, RunState::Paused | RunState::Over) {
+    if matches!(g.run_state, RunState::Paused | RunState::Over | RunState::Won | RunState::Countdown(_)) {
         return;
     }
 
-    let next = next_head(g.snake.body.front().copied().unwrap(), g.snake.dir);
-
-    // Handle wall collisions or wrapping
-    #[cfg(feature = "wrap_walls")]
-    let wrapped_next = if g.wrap_walls && out_of_bounds(next, g.grid) {
-        wrap_position(next, g.grid)
-    } else {
-        next
-    };
+    if g.snake.body.is_empty() {
+        g.run_state = RunState::Over;
+        return;
+    }
 
-    #[cfg(not(feature = "wrap_walls"))]
-    let wrapped_next = next;
+    // A snake that already covers every cell (e.g. on a 1x1 grid) has nowhere to go
+    if g.snake.body.len() >= g.grid.area() {
+        g.run_state = RunState::Over;
+        return;
+    }
 
-    // Check for wall collisions (when wrap_walls is disabled or feature not available)
+    // Moving through an edge rather than off the grid means the head wrapped
     #[cfg(feature = "wrap_walls")]
-    let should_end_game = !g.wrap_walls && out_of_bounds(next, g.grid);
-
-    #[cfg(not(feature = "wrap_walls"))]
-    let should_end_game = out_of_bounds(next, g.grid);
+    let wrapped = g.wall_behavior == WallBehavior::Wrap
+        && out_of_bounds(next_head(g.snake.head(), g.snake.dir), g.grid);
 
-    if should_end_game {
-        g.run_state = RunState::Over;
+    // Resolve wall hits according to the configured behavior
+    let Some((wrapped_next, dir)) = target_cell(g, g.snake.dir) else {
+        let off_grid = next_head(g.snake.head(), g.snake.dir);
+        crash(g, rng, GameOverReason::Wall, off_grid);
         return;
-    }
+    };
 
     // Check for self collisions
-    if g.snake.body.iter().any(|&p| p == wrapped_next) {
-        g.run_state = RunState::Over;
+    if hits_body(g, wrapped_next) {
+        crash(g, rng, GameOverReason::SelfCollision, wrapped_next);
         return;
     }
+    g.snake.dir = dir;
 
     g.snake.body.push_front(wrapped_next);
+    g.record_move();
+    g.record_trail(wrapped_next);
+    g.tick += 1;
+
+    // Portal edges: wrapping is allowed but may cost points
+    #[cfg(feature = "wrap_walls")]
+    if wrapped {
+        g.last_wrap = Some((dir, g.tick));
+        if let Some(cost) = g.wrap_cost {
+            g.deduct_score(cost);
+        }
+    }
 
     // Check if food is eaten (using wrapped position)
     #[cfg(not(feature = "multiple_foods"))]
     {
         if wrapped_next == g.food {
-            g.score += 1;
-            g.food = spawn_food(&g.grid, &g.snake, rng);
+            g.add_score(food_points(g, 1, wrapped_next));
+            g.record_eat(wrapped_next);
+            if g.no_grow {
+                g.snake.body.pop_back();
+            }
+            g.use_food_quota();
+            if !g.is_won() {
+                let prey = g.roaming_food.as_slice();
+                match spawn_food(&g.grid, &g.snake, rng, prey, g.spawn_min_head_dist) {
+                    Some(food) => {
+                        g.food = food;
+                        g.record_spawn(food);
+                    }
+                    // The snake now fills the grid: nothing left to eat
+                    None => g.run_state = RunState::Over,
+                }
+            }
         } else {
             g.snake.body.pop_back();
         }
@@ -57,112 +97,336 @@ pub fn step<R: RngLike>(g: &mut GameState, rng: &mut R) {
 
     #[cfg(feature = "multiple_foods")]
     {
-        let mut food_eaten = false;
-        
-        // Check if snake head collides with any food
-        if let Some(food_index) = g.foods.iter().position(|f| f.position == wrapped_next) {
-            let eaten_food = g.foods.remove(food_index);
-            let points_earned = eaten_food.food_type.point_value();
-            g.score += points_earned;
-            food_eaten = true;
-            
-            // Spawn a new food to maintain food count (keep 3-5 foods on grid)
-            if g.foods.len() < 5 {
-                let new_food = spawn_food_with_type(&g.grid, &g.snake, rng, &g.foods);
-                g.foods.push(new_food);
+        // Hit every food on the head cell (normally at most one), in list order; armored
+        // food stays put until its last hit
+        let (hit, remaining): (Vec<Food>, Vec<Food>) = std::mem::take(&mut g.foods)
+            .into_iter()
+            .partition(|f| f.position == wrapped_next);
+        g.foods = remaining;
+        let mut eaten = Vec::new();
+        for mut food in hit {
+            food.hits_remaining = food.hits_remaining.saturating_sub(1);
+            if food.hits_remaining > 0 {
+                g.foods.push(food);
+            } else {
+                eaten.push(food);
             }
         }
-        
-        if !food_eaten {
+        let food_eaten = !eaten.is_empty();
+
+        for eaten_food in &eaten {
+            let points_earned = eaten_food.food_type.point_value() + streak_bonus(g, eaten_food.food_type);
+            g.add_score(food_points(g, points_earned, wrapped_next));
+            g.use_food_quota();
+
+            // Replace the eaten food while below the maximum, until the quota is cleared
+            if g.foods.len() < g.max_foods && !g.is_won() {
+                let prey = g.roaming_food.as_slice();
+                let dist = g.spawn_min_head_dist;
+                if let Some(new_food) = spawn_food_with_type(&g.grid, &g.snake, rng, &g.foods, prey, dist) {
+                    g.add_spawned_food(new_food);
+                }
+            }
+        }
+        if food_eaten {
+            g.record_eat(wrapped_next);
+            if g.no_grow {
+                g.snake.body.pop_back();
+            } else {
+                // Keeping the tail this step is the first segment; the rest follow on later steps
+                let growth: u32 = eaten.iter().map(|f| f.growth).sum();
+                g.pending_growth += growth.saturating_sub(1);
+            }
+            maintain_food_count(g, rng);
+        } else if g.pending_growth > 0 {
+            g.pending_growth -= 1;
+        } else {
             g.snake.body.pop_back();
         }
     }
+
+    // Catching the roaming prey scores a bonus (without growing) and releases a new one
+    if g.roaming_food == Some(wrapped_next) {
+        g.add_score(food_points(g, ROAMING_FOOD_POINTS, wrapped_next));
+        g.roaming_food = random_free_cell(g, rng).or(g.roaming_food);
+    }
+
+    // Risk/reward: hugging the walls slowly drains the score
+    if let Some(penalty) = g.graze_penalty {
+        if on_edge(wrapped_next, g.grid) {
+            g.deduct_score(penalty);
+        }
+    }
+}
+
+/// Food point multiplier for a grid: one extra step per 100 cells, never below 1
+pub fn grid_value_factor(grid: GridSize) -> u32 {
+    (grid.area() / 100).max(1) as u32
 }
 
-fn next_head(head: Position, dir: Direction) -> Position {
-    match dir {
-        Direction::Up => Position {
-            x: head.x,
-            y: head.y - 1,
-        },
-        Direction::Down => Position {
-            x: head.x,
-            y: head.y + 1,
-        },
-        Direction::Left => Position {
-            x: head.x - 1,
-            y: head.y,
-        },
-        Direction::Right => Position {
-            x: head.x + 1,
-            y: head.y,
-        },
+/// Points for eating food worth `base` at `pos`: scaled by grid size when
+/// `food_value_scale` is on, and doubled inside the bonus zone
+fn food_points(g: &GameState, base: u32, pos: Position) -> u32 {
+    let mut points = base;
+    if g.food_value_scale {
+        points *= grid_value_factor(g.grid);
     }
+    if g.in_bonus_zone(pos) {
+        points *= 2;
+    }
+    points
 }
 
-fn out_of_bounds(p: Position, g: GridSize) -> bool {
-    p.x < 0 || p.y < 0 || p.x >= g.w || p.y >= g.h
+/// Move the roaming food one cell in a random direction, avoiding walls, the snake and
+/// other food; it stays put when boxed in or while the game isn't running
+pub fn advance_roaming_food<R: RngLike>(g: &mut GameState, rng: &mut R) {
+    let Some(prey) = g.roaming_food else {
+        return;
+    };
+    if !g.is_running() {
+        return;
+    }
+    let options: Vec<Position> = [Direction::Up, Direction::Down, Direction::Left, Direction::Right]
+        .into_iter()
+        .map(|dir| next_head(prey, dir))
+        .filter(|&p| !out_of_bounds(p, g.grid) && is_free(g, p))
+        .collect();
+    if !options.is_empty() {
+        g.roaming_food = Some(options[rng.next_range(options.len() as u32) as usize]);
+    }
 }
 
-/// Wrap a position that is out of bounds to the opposite side (toroidal topology)
-#[cfg(feature = "wrap_walls")]
-fn wrap_position(p: Position, g: GridSize) -> Position {
-    Position {
-        x: p.x.rem_euclid(g.w),
-        y: p.y.rem_euclid(g.h),
+/// Cell holding neither the snake nor any food
+fn is_free(g: &GameState, p: Position) -> bool {
+    !g.food_positions().contains(&p) && !g.snake.body.contains(&p)
+}
+
+/// Uniformly chosen free cell, or `None` when the board is full
+pub(crate) fn random_free_cell<R: RngLike>(g: &GameState, rng: &mut R) -> Option<Position> {
+    let free: Vec<Position> = (0..g.grid.h)
+        .flat_map(|y| (0..g.grid.w).map(move |x| Position { x, y }))
+        .filter(|&p| is_free(g, p))
+        .collect();
+    if free.is_empty() {
+        return None;
     }
+    Some(free[rng.next_range(free.len() as u32) as usize])
 }
 
-fn spawn_food<R: RngLike>(grid: &GridSize, snake: &Snake, rng: &mut R) -> Position {
-    // sample until empty cell found (grid small → inexpensive; tests cover termination)
-    loop {
-        let x = (rng.next_u32() as i32).rem_euclid(grid.w);
-        let y = (rng.next_u32() as i32).rem_euclid(grid.h);
-        let p = Position { x, y };
-        if !snake.body.iter().any(|&s| s == p) {
-            return p;
-        }
+/// Whether the next step will eat food, without changing the game
+///
+/// False when the game isn't running or the step would be fatal.
+pub fn would_eat(g: &GameState) -> bool {
+    if !g.is_running() || g.snake.body.is_empty() || g.snake.body.len() >= g.grid.area() {
+        return false;
+    }
+    let Some((next, _)) = target_cell(g, g.snake.dir) else {
+        return false;
+    };
+    if hits_body(g, next) {
+        return false;
     }
+    #[cfg(not(feature = "multiple_foods"))]
+    let eats = g.food == next;
+    // Armored food only cracks on contact, so it isn't eaten yet
+    #[cfg(feature = "multiple_foods")]
+    let eats = g.foods.iter().any(|f| f.position == next && f.hits_remaining <= 1);
+    eats
 }
 
-#[cfg(feature = "multiple_foods")]
-fn spawn_food_with_type<R: RngLike>(
-    grid: &GridSize,
-    snake: &Snake,
-    rng: &mut R,
-    existing_foods: &[Food],
-) -> Food {
-    let food_type = determine_food_type(rng);
-    
-    loop {
-        let x = (rng.next_u32() as i32).rem_euclid(grid.w);
-        let y = (rng.next_u32() as i32).rem_euclid(grid.h);
-        let p = Position { x, y };
-
-        // Check not on snake and not on existing foods
-        if !snake.body.iter().any(|&s| s == p)
-            && !existing_foods.iter().any(|f| f.position == p)
-        {
-            return Food {
-                position: p,
-                food_type,
+/// Cell the head moves into when heading `dir`, and the direction it will then be facing,
+/// or `None` when it hits a fatal wall
+fn target_cell(g: &GameState, dir: Direction) -> Option<(Position, Direction)> {
+    let head = g.snake.head();
+    let next = next_head(head, dir);
+    if !out_of_bounds(next, g.grid) {
+        return Some((next, dir));
+    }
+
+    match g.wall_behavior {
+        WallBehavior::Die => None,
+        #[cfg(feature = "wrap_walls")]
+        WallBehavior::Wrap => Some((wrap_position(next, g.grid), dir)),
+        WallBehavior::Bounce => {
+            // Reflect off the wall; when the reversed direction runs into the neck (any
+            // snake longer than one segment), turn to the first free perpendicular instead
+            let reflected = dir.opposite();
+            let perpendicular = match dir {
+                Direction::Left | Direction::Right => [Direction::Up, Direction::Down],
+                Direction::Up | Direction::Down => [Direction::Left, Direction::Right],
+            };
+            let open = |d: Direction| {
+                let cell = next_head(head, d);
+                (!out_of_bounds(cell, g.grid)).then_some((cell, d))
             };
+            std::iter::once(reflected)
+                .chain(perpendicular)
+                .filter_map(open)
+                .find(|&(cell, _)| !g.snake.body.contains(&cell))
+                .or_else(|| open(reflected))
+        }
+    }
+}
+
+/// Whether stepping in `dir` would be a fatal collision (wall or body)
+fn is_fatal(g: &GameState, dir: Direction) -> bool {
+    match target_cell(g, dir) {
+        Some((next, _)) => hits_body(g, next),
+        None => true,
+    }
+}
+
+/// Whether moving the head into `next` runs into the snake's own body
+///
+/// The tail normally still counts as body: it only moves out of the way after the head
+/// has moved, so running into it is fatal. With wrapping walls a snake can chase its tail
+/// around a loop through an edge, so there the tail is free whenever this step won't grow
+/// the snake.
+fn hits_body(g: &GameState, next: Position) -> bool {
+    #[cfg(feature = "wrap_walls")]
+    if g.wall_behavior == WallBehavior::Wrap && next == g.snake.tail() && tail_moves(g, next) {
+        return g.snake.body.iter().rev().skip(1).any(|&p| p == next);
+    }
+    g.snake.body.contains(&next)
+}
+
+/// Whether the tail leaves its cell on a step moving the head into `next`
+#[cfg(feature = "wrap_walls")]
+fn tail_moves(g: &GameState, next: Position) -> bool {
+    #[cfg(not(feature = "multiple_foods"))]
+    let grows = next == g.food && !g.no_grow;
+    #[cfg(feature = "multiple_foods")]
+    let grows = if g.foods.iter().any(|f| f.position == next && f.hits_remaining <= 1) {
+        !g.no_grow
+    } else {
+        g.pending_growth > 0
+    };
+    !grows
+}
+
+/// Directions `apply_direction` would accept, in Up/Down/Left/Right order; with `strict`,
+/// only those whose next step isn't fatal
+///
+/// Once the `max_turns` budget is spent, that leaves only `last_dir`.
+pub fn legal_dirs(g: &GameState, strict: bool) -> Vec<Direction> {
+    let budget_spent = g.turn_budget_spent();
+    [Direction::Up, Direction::Down, Direction::Left, Direction::Right]
+        .into_iter()
+        .filter(|&dir| {
+            let reversal = dir == g.last_dir.opposite() && g.snake.body.len() > 1;
+            !reversal || g.allow_reversal
+        })
+        .filter(|&dir| dir == g.last_dir || !budget_spent)
+        .filter(|&dir| !strict || (!g.snake.body.is_empty() && !is_fatal(g, dir)))
+        .collect()
+}
+
+/// Handle a fatal collision at `cell`: spend a life and respawn at the center, or end
+/// the game and remember `cell` as the `death_cell`
+///
+/// Score and food are kept across respawns; only food (or the roaming prey) under the new
+/// head is moved. In peaceful mode nothing happens: the snake just doesn't move this step.
+fn crash<R: RngLike>(g: &mut GameState, rng: &mut R, reason: GameOverReason, cell: Position) {
+    if g.peaceful {
+        return;
+    }
+    if g.lives == 0 {
+        g.set_over_by(reason);
+        g.death_cell = Some(cell);
+        return;
+    }
+    g.lives -= 1;
+
+    let start = g.grid.center();
+    g.snake.body.clear();
+    g.snake.body.push_back(start);
+    g.snake.dir = Direction::Right;
+    g.last_dir = Direction::Right;
+    g.straight_run = 0;
+
+    #[cfg(not(feature = "multiple_foods"))]
+    if g.food == start {
+        let prey = g.roaming_food.as_slice();
+        if let Some(food) = spawn_food(&g.grid, &g.snake, rng, prey, g.spawn_min_head_dist) {
+            g.food = food;
+            g.record_spawn(food);
         }
     }
+
+    #[cfg(feature = "multiple_foods")]
+    {
+        g.pending_growth = 0;
+        g.foods.retain(|f| f.position != start);
+        maintain_food_count(g, rng);
+    }
+
+    if g.roaming_food == Some(start) {
+        g.roaming_food = random_free_cell(g, rng);
+    }
 }
 
+/// Track same-type eating streaks; continuing a streak is worth `streak_len` bonus points
 #[cfg(feature = "multiple_foods")]
-fn determine_food_type<R: RngLike>(rng: &mut R) -> FoodType {
-    // Spawn probabilities:
-    // Normal: 70% (0-69)
-    // Golden: 25% (70-94)
-    // Special: 5% (95-99)
-    let roll = rng.next_u32() % 100;
-    if roll < 70 {
-        FoodType::Normal
-    } else if roll < 95 {
-        FoodType::Golden
+fn streak_bonus(g: &mut GameState, food_type: FoodType) -> u32 {
+    if g.streak_type == Some(food_type) {
+        g.streak_len += 1;
+        g.streak_len
     } else {
-        FoodType::Special
+        g.streak_type = Some(food_type);
+        g.streak_len = 1;
+        0
+    }
+}
+
+/// Spawn foods until at least `g.min_foods` are on the grid (or no cell is free)
+#[cfg(feature = "multiple_foods")]
+pub fn maintain_food_count<R: RngLike>(g: &mut GameState, rng: &mut R) {
+    #[cfg(not(feature = "cluster_food"))]
+    while g.foods.len() < g.min_foods {
+        let prey = g.roaming_food.as_slice();
+        match spawn_food_with_type(&g.grid, &g.snake, rng, &g.foods, prey, g.spawn_min_head_dist) {
+            Some(food) => g.add_spawned_food(food),
+            None => break,
+        }
+    }
+
+    // Refill in whole clusters, trimmed so the total stays within `max_foods`
+    #[cfg(feature = "cluster_food")]
+    while g.foods.len() < g.min_foods {
+        let size = CLUSTER_SIZE.min(g.max_foods.max(g.min_foods) - g.foods.len());
+        let prey = g.roaming_food.as_slice();
+        let dist = g.spawn_min_head_dist;
+        let cluster = spawn_cluster_clear_of_head(&g.grid, &g.snake, rng, &g.foods, prey, size, dist);
+        if cluster.is_empty() {
+            break;
+        }
+        for food in cluster {
+            g.add_spawned_food(food);
+        }
+    }
+}
+
+/// Cell the head moves into heading `dir`, before any wall handling (may lie off the grid)
+pub fn next_head(head: Position, dir: Direction) -> Position {
+    head.step(dir)
+}
+
+/// Whether `p` lies outside the grid
+pub fn out_of_bounds(p: Position, g: GridSize) -> bool {
+    p.x < 0 || p.y < 0 || p.x >= g.w || p.y >= g.h
+}
+
+/// True for cells in the outermost row or column of the grid
+fn on_edge(p: Position, g: GridSize) -> bool {
+    p.x == 0 || p.y == 0 || p.x == g.w - 1 || p.y == g.h - 1
+}
+
+/// Wrap a position that is out of bounds to the opposite side (toroidal topology)
+///
+/// In-bounds positions are returned unchanged.
+pub fn wrap_position(p: Position, g: GridSize) -> Position {
+    Position {
+        x: p.x.rem_euclid(g.w),
+        y: p.y.rem_euclid(g.h),
     }
 }
@@ -1,36 +1,110 @@
-use crate::{rng::RngLike, state::GameState, types::GridSize};
+use crate::{rng::RngLike, state::GameState, types::{GridError, GridSize}};
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Settings {
     pub grid: GridSize,
     pub speed: u32, // logical speed units (e.g., ticks per second)
+    #[serde(default)]
+    pub allow_reversal: bool, // hard mode: reversing into the body is fatal
+    #[serde(default = "default_min_foods")]
+    pub min_foods: usize, // multiple_foods: respawn keeps at least this many
+    #[serde(default = "default_max_foods")]
+    pub max_foods: usize, // multiple_foods: never respawn above this many
+    #[serde(default)]
+    pub starting_score: i64, // negative only takes effect with the signed_score feature
+}
+
+fn default_min_foods() -> usize {
+    3
+}
+
+fn default_max_foods() -> usize {
+    5
+}
+
+/// Color palette selection for frontends (accessibility options)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Palette {
+    #[default]
+    Default,
+    Deuteranopia,
+    HighContrast,
+}
+
+impl Palette {
+    /// Next palette in cycling order, for a single toggle key
+    pub fn next(self) -> Palette {
+        match self {
+            Palette::Default => Palette::Deuteranopia,
+            Palette::Deuteranopia => Palette::HighContrast,
+            Palette::HighContrast => Palette::Default,
+        }
+    }
+
+    /// Stable name used in saved profiles
+    pub fn name(self) -> &'static str {
+        match self {
+            Palette::Default => "default",
+            Palette::Deuteranopia => "deuteranopia",
+            Palette::HighContrast => "high_contrast",
+        }
+    }
+
+    /// Palette with the given `name`, if any
+    pub fn from_name(name: &str) -> Option<Palette> {
+        [Palette::Default, Palette::Deuteranopia, Palette::HighContrast]
+            .into_iter()
+            .find(|p| p.name() == name)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum SettingsError {
     InvalidGridWidth(i32),
     InvalidGridHeight(i32),
+    GridTooLarge { area: usize, max: usize },
     InvalidSpeed(u32),
+    InvalidFoodCounts { min: usize, max: usize },
+}
+
+impl From<GridError> for SettingsError {
+    fn from(err: GridError) -> Self {
+        match err {
+            GridError::InvalidWidth(w) => SettingsError::InvalidGridWidth(w),
+            GridError::InvalidHeight(h) => SettingsError::InvalidGridHeight(h),
+            GridError::TooLarge { area, max } => SettingsError::GridTooLarge { area, max },
+        }
+    }
 }
 
 impl Default for Settings {
     fn default() -> Self {
-        Self { grid: GridSize { w: 10, h: 10 }, speed: 10 }
+        Self {
+            grid: GridSize { w: 10, h: 10 },
+            speed: 10,
+            allow_reversal: false,
+            min_foods: default_min_foods(),
+            max_foods: default_max_foods(),
+            starting_score: 0,
+        }
     }
 }
 
 impl Settings {
     pub fn new(grid: GridSize, speed: u32) -> Result<Self, SettingsError> {
-        let candidate = Self { grid, speed };
+        let candidate = Self { grid, speed, ..Self::default() };
         candidate.validate()?;
         Ok(candidate)
     }
 
     pub fn validate(&self) -> Result<(), SettingsError> {
-        if self.grid.w <= 0 { return Err(SettingsError::InvalidGridWidth(self.grid.w)); }
-        if self.grid.h <= 0 { return Err(SettingsError::InvalidGridHeight(self.grid.h)); }
+        GridSize::try_new(self.grid.w, self.grid.h)?;
         // Allow a reasonable speed range for tests and UI; can be adjusted later
         if self.speed == 0 || self.speed > 60 { return Err(SettingsError::InvalidSpeed(self.speed)); }
+        if self.min_foods == 0 || self.min_foods > self.max_foods {
+            return Err(SettingsError::InvalidFoodCounts { min: self.min_foods, max: self.max_foods });
+        }
         Ok(())
     }
 
@@ -46,13 +120,40 @@ impl Settings {
         Ok(self)
     }
 
-    pub fn apply_to_new_game<R: RngLike>(&self, rng: R) -> GameState {
+    pub fn with_food_counts(mut self, min_foods: usize, max_foods: usize) -> Result<Self, SettingsError> {
+        self.min_foods = min_foods;
+        self.max_foods = max_foods;
+        self.validate()?;
+        Ok(self)
+    }
+
+    pub fn with_allow_reversal(mut self, allow_reversal: bool) -> Self {
+        self.allow_reversal = allow_reversal;
+        self
+    }
+
+    pub fn with_starting_score(mut self, starting_score: i64) -> Self {
+        self.starting_score = starting_score;
+        self
+    }
+
+    pub fn apply_to_new_game<R: RngLike>(&self, mut rng: R) -> GameState {
         // Validation is expected to be enforced by constructors; in case of misuse, clamp at runtime isn't applied here.
-        GameState::new(self.grid, rng)
+        let mut game = GameState::new(self.grid, &mut rng);
+        game.allow_reversal = self.allow_reversal;
+        game.set_score(self.starting_score);
+        #[cfg(feature = "multiple_foods")]
+        {
+            game.min_foods = self.min_foods;
+            game.max_foods = self.max_foods;
+            game.foods.truncate(self.max_foods);
+            crate::rules::maintain_food_count(&mut game, &mut rng);
+        }
+        game
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct SettingsStore {
     settings: Settings,
 }
@@ -63,8 +164,6 @@ impl SettingsStore {
         Ok(Self { settings })
     }
 
-    pub fn default() -> Self { Self { settings: Settings::default() } }
-
     pub fn get(&self) -> Settings { self.settings }
 
     pub fn update(&mut self, new_settings: Settings) -> Result<(), SettingsError> {
@@ -0,0 +1,75 @@
+//! Headless simulation: play scripted games to completion and aggregate the results
+//!
+//! Everything is driven by `Seeded`, so a settings/seed/script triple always
+//! produces the same outcome.
+
+use crate::{
+    rng::Seeded,
+    rules,
+    settings::Settings,
+    state::GameOverReason,
+    types::Direction,
+};
+use std::collections::HashMap;
+
+/// Outcome of one simulated game
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SimResult {
+    pub score: u32,
+    /// Steps played before the game ended or `max_ticks` ran out
+    pub ticks: usize,
+    /// What ended the game; `None` if it survived `max_ticks` or filled the board
+    pub death_cause: Option<GameOverReason>,
+}
+
+/// Aggregate over many simulated games
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SweepStats {
+    pub games: usize,
+    pub mean_score: f64,
+    pub min_score: u32,
+    pub max_score: u32,
+    /// Games per `SimResult::death_cause`
+    pub death_causes: HashMap<Option<GameOverReason>, usize>,
+}
+
+/// Play one game from `settings` and `seed`, applying `directions[t % len]` before step
+/// `t` (an empty script keeps the starting heading), for at most `max_ticks` steps
+pub fn simulate(settings: &Settings, seed: u64, directions: &[Direction], max_ticks: usize) -> SimResult {
+    let mut rng = Seeded::new(seed);
+    let mut game = settings.apply_to_new_game(&mut rng);
+    let mut ticks = 0;
+    while ticks < max_ticks && !game.is_over() {
+        if !directions.is_empty() {
+            game.apply_direction(directions[ticks % directions.len()]);
+        }
+        rules::step(&mut game, &mut rng);
+        ticks += 1;
+    }
+    SimResult { score: game.score, ticks, death_cause: game.game_over_reason() }
+}
+
+/// `simulate` every seed with the same settings and script and summarize the results
+pub fn sweep(
+    settings: &Settings,
+    seeds: impl Iterator<Item = u64>,
+    directions: &[Direction],
+    max_ticks: usize,
+) -> SweepStats {
+    let mut stats = SweepStats { min_score: u32::MAX, ..SweepStats::default() };
+    let mut total: u64 = 0;
+    for seed in seeds {
+        let result = simulate(settings, seed, directions, max_ticks);
+        stats.games += 1;
+        total += result.score as u64;
+        stats.min_score = stats.min_score.min(result.score);
+        stats.max_score = stats.max_score.max(result.score);
+        *stats.death_causes.entry(result.death_cause).or_default() += 1;
+    }
+    if stats.games == 0 {
+        stats.min_score = 0;
+    } else {
+        stats.mean_score = total as f64 / stats.games as f64;
+    }
+    stats
+}
@@ -1,6 +1,6 @@
-use crate::{rng::RngLike, types::*};
+use crate::{rng::{RngLike, Seeded}, rules::out_of_bounds, types::*};
 #[cfg(feature = "multiple_foods")]
-use crate::types::{Food, FoodType};
+use crate::types::{Food, FoodType, FOOD_WEIGHTS};
 use std::collections::VecDeque;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -9,13 +9,120 @@ pub struct Snake {
     pub dir: Direction,
 }
 
+impl Snake {
+    /// First body segment
+    ///
+    /// Panics on an empty body; every snake the game builds has at least one segment.
+    pub fn head(&self) -> Position {
+        *self.body.front().expect("snake has no segments")
+    }
+
+    /// Last body segment; the head itself for a single-segment snake
+    ///
+    /// Panics on an empty body, like [`Snake::head`].
+    pub fn tail(&self) -> Position {
+        *self.body.back().expect("snake has no segments")
+    }
+
+    /// Whether the body is a plausible snake on `grid`: at least one segment, every cell
+    /// inside the grid and distinct, and each segment one step (Manhattan 1) from the next
+    ///
+    /// For checking imported or hand-edited states. Edges count as solid, so a body that
+    /// has wrapped around an edge is not contiguous.
+    pub fn is_valid(&self, grid: GridSize) -> bool {
+        !self.body.is_empty()
+            && self.body.iter().all(|&p| !out_of_bounds(p, grid))
+            && self.body.iter().zip(self.body.iter().skip(1)).all(|(a, b)| a.manhattan(*b) == 1)
+            && self.body.iter().enumerate().all(|(i, p)| !self.body.iter().skip(i + 1).any(|q| q == p))
+    }
+
+    /// Cells enclosed by the body when the head touches the tail, in row-major order
+    ///
+    /// `None` unless the snake forms a closed loop around at least one cell. Enclosed cells
+    /// are the free cells a flood fill from the grid border cannot reach.
+    pub fn encloses(&self, grid: GridSize) -> Option<Vec<Position>> {
+        let (&head, &tail) = (self.body.front()?, self.body.back()?);
+        if self.body.len() < 4 || head.manhattan(tail) != 1 {
+            return None;
+        }
+
+        let index = |p: Position| (p.y * grid.w + p.x) as usize;
+        let mut outside = vec![false; grid.area()];
+        let mut queue: VecDeque<Position> = (0..grid.h)
+            .flat_map(|y| (0..grid.w).map(move |x| Position { x, y }))
+            .filter(|p| p.x == 0 || p.y == 0 || p.x == grid.w - 1 || p.y == grid.h - 1)
+            .filter(|p| !self.body.contains(p))
+            .collect();
+        for &p in &queue {
+            outside[index(p)] = true;
+        }
+
+        while let Some(p) = queue.pop_front() {
+            for (dx, dy) in [(0, -1), (0, 1), (-1, 0), (1, 0)] {
+                let n = Position { x: p.x + dx, y: p.y + dy };
+                if !out_of_bounds(n, grid) && !outside[index(n)] && !self.body.contains(&n) {
+                    outside[index(n)] = true;
+                    queue.push_back(n);
+                }
+            }
+        }
+
+        let interior: Vec<Position> = (0..grid.h)
+            .flat_map(|y| (0..grid.w).map(move |x| Position { x, y }))
+            .filter(|&p| !outside[index(p)] && !self.body.contains(&p))
+            .collect();
+        (!interior.is_empty()).then_some(interior)
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum RunState {
     Running,
     Paused,
     Over,
+    /// Pre-start phase: the snake waits this many more ticks before the game runs
+    Countdown(u8),
+    /// The game ended in a win; `GameState::win_reason` says how
+    Won,
 }
 
+/// How the game was won
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WinReason {
+    /// Still alive when `time_limit_ticks` ran out
+    TimeSurvived,
+    /// Ate the last food of the `foods_remaining` quota
+    QuotaCleared,
+}
+
+/// What ended the game
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GameOverReason {
+    Wall,
+    SelfCollision,
+}
+
+/// What happens when the snake's head runs into the edge of the grid
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum WallBehavior {
+    /// The collision is fatal
+    #[default]
+    Die,
+    /// The head re-enters on the opposite side
+    #[cfg(feature = "wrap_walls")]
+    Wrap,
+    /// The snake reverses direction and moves away from the wall, or turns aside when its
+    /// own neck is in the way
+    Bounce,
+}
+
+/// Default lower bound on foods kept on the grid
+#[cfg(feature = "multiple_foods")]
+pub const DEFAULT_MIN_FOODS: usize = 3;
+/// Default upper bound on foods kept on the grid
+#[cfg(feature = "multiple_foods")]
+pub const DEFAULT_MAX_FOODS: usize = 5;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GameState {
     pub grid: GridSize,
@@ -24,206 +131,939 @@ pub struct GameState {
     pub food: Position,
     #[cfg(feature = "multiple_foods")]
     pub foods: Vec<Food>,
+    /// Respawning keeps at least this many foods on the grid
+    #[cfg(feature = "multiple_foods")]
+    pub min_foods: usize,
+    #[cfg(feature = "multiple_foods")]
+    pub max_foods: usize,
+    /// Type of the last food eaten, for same-type streak bonuses
+    #[cfg(feature = "multiple_foods")]
+    pub streak_type: Option<FoodType>,
+    /// How many foods of `streak_type` were eaten in a row
+    #[cfg(feature = "multiple_foods")]
+    pub streak_len: u32,
+    /// Golden food rush: foods spawned while `tick` is below this are all Golden. Cleared
+    /// by `reset`.
+    #[cfg(feature = "multiple_foods")]
+    pub golden_rush_until: Option<u64>,
+    /// Segments still to be added from food with `growth` above 1; each step that eats
+    /// nothing keeps the tail instead of popping it and uses one up
+    #[cfg(feature = "multiple_foods")]
+    pub pending_growth: u32,
     pub score: u32,
+    /// Signed score that penalties can push below zero; with this feature it is the real
+    /// score and `score` just mirrors it, floored at 0
+    #[cfg(feature = "signed_score")]
+    pub score_i: i64,
     pub run_state: RunState,
+    pub wall_behavior: WallBehavior,
+    /// Points lost each time the head wraps around an edge (floored at 0)
+    #[cfg(feature = "wrap_walls")]
+    pub wrap_cost: Option<u32>,
+    /// Direction and `tick` of the most recent wrap, for flashing the edge it went through
     #[cfg(feature = "wrap_walls")]
-    pub wrap_walls: bool,
+    pub last_wrap: Option<(Direction, u64)>,
+    /// Accept 180-degree turns in `apply_direction` ("hard mode")
+    pub allow_reversal: bool,
+    /// Number of successful moves since the game started
+    pub tick: u64,
+    /// Points lost on every step that ends with the head next to a wall (floored at 0)
+    pub graze_penalty: Option<u32>,
+    /// Seed of the `Seeded` RNG this round was built from, when known (`from_seed`/`reseed`)
+    pub seed: Option<u64>,
+    /// Stream `reset_continue` draws from, seeded by the RNG passed to `new`/`reset`
+    session_rng: Seeded,
+    /// Extra lives left: a fatal collision with lives left respawns the snake instead of
+    /// ending the game
+    pub lives: u32,
+    /// Extra lives each round starts with; `reset` refills `lives` from it
+    pub starting_lives: u32,
+    /// Food never spawns closer than this (Manhattan) to the head, unless no cell qualifies
+    pub spawn_min_head_dist: i32,
+    /// Prey that random-walks one cell per loop update; eating it is worth
+    /// `rules::ROAMING_FOOD_POINTS`. `None` disables it.
+    pub roaming_food: Option<Position>,
+    /// Fatal moves are ignored instead: the head stays put and the game goes on
+    pub peaceful: bool,
+    /// Multiply food points by `rules::grid_value_factor` so big grids score bigger
+    pub food_value_scale: bool,
+    /// Debug aid: eating still scores but never grows the snake
+    pub no_grow: bool,
+    /// Hold the snake still at the start of each round until the player presses a direction
+    pub wait_for_input: bool,
+    /// Whether this round's first input has arrived (see `wait_for_input`)
+    pub first_input_seen: bool,
+    /// Cells where food was eaten most recently, oldest first (at most `RECENT_EATS_CAP`)
+    pub recent_eats: VecDeque<Position>,
+    /// How many past head cells `trail_cells` keeps for analytics overlays (0 disables)
+    pub trail_cap: usize,
+    /// Head cells visited this round, oldest first
+    trail: VecDeque<Position>,
+    /// Opposite corners of a region (borders included) where eaten food scores double
+    pub bonus_zone: Option<(Position, Position)>,
+    /// Direction the snake actually moved on the last step
+    pub last_dir: Direction,
+    /// Momentum mode: once `straight_run` reaches this many steps, `Loop::update` moves the
+    /// snake twice per update until it turns. `None` disables it.
+    pub momentum_after: Option<u32>,
+    /// Steps moved since the last turn (or the start of the round)
+    pub straight_run: u32,
+    /// Turn-limiting challenge: `apply_direction` ignores turns beyond this budget
+    pub max_turns: Option<TurnLimit>,
+    /// Ticks at which the snake turned, oldest first (only tracked for `max_turns`)
+    recent_turns: VecDeque<u64>,
+    game_over_reason: Option<GameOverReason>,
+    /// "Survive N ticks" mode: reaching this `tick` alive wins the game. `None` disables it.
+    pub time_limit_ticks: Option<u64>,
+    /// Score attack: foods still to eat; eating the last one wins the game. `None` disables
+    /// it.
+    pub foods_remaining: Option<u32>,
+    /// Foods each round's score attack starts with; `reset` refills `foods_remaining` from it
+    pub food_quota: Option<u32>,
+    win_reason: Option<WinReason>,
+    /// Cell the head was moving into when a collision ended the game (off the grid for
+    /// a wall hit)
+    pub death_cell: Option<Position>,
+    /// Food spawns per cell this round (row-major, `y * grid.w + x`), for tuning spawn
+    /// fairness
+    #[cfg(feature = "heatmap")]
+    pub spawn_heat: Vec<u32>,
+}
+
+/// How many eaten-food cells `GameState::recent_eats` remembers
+pub const RECENT_EATS_CAP: usize = 5;
+
+/// Why `GameState::validate_placement` rejected a board; each names the offending cell
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlacementError {
+    OutOfBounds(Position),
+    /// Two snake segments share a cell
+    SnakeOverlap(Position),
+    FoodOnSnake(Position),
+    /// Two foods (including the roaming food) share a cell
+    FoodOverlap(Position),
+}
+
+/// At most `turns` turns within any `window_ticks` consecutive steps
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TurnLimit {
+    pub turns: u32,
+    pub window_ticks: u64,
+}
+
+impl Default for GameState {
+    /// A 20x20 game seeded with 0, handy for benches and tests
+    fn default() -> Self {
+        GameState::new(GridSize { w: 20, h: 20 }, Seeded::new(0))
+    }
+}
+
+/// Minimal description of what changed between two snapshots of a game
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StateDiff {
+    /// New head position, if the head moved
+    pub head_moved: Option<Position>,
+    pub score_delta: i64,
+    pub food_changed: bool,
+    /// The game went from not finished to finished, lost or won
+    pub ended: bool,
 }
 
 impl GameState {
-    #[cfg(not(feature = "wrap_walls"))]
     pub fn new<R: RngLike>(grid: GridSize, rng: R) -> Self {
-        Self::new_with_wrap(grid, rng, false)
+        Self::build(grid, rng, WallBehavior::Die)
+    }
+
+    /// New game from `Seeded::new(seed)`, remembering the seed so it can be shown and shared
+    pub fn from_seed(grid: GridSize, seed: u64) -> Self {
+        let mut g = Self::new(grid, Seeded::new(seed));
+        g.seed = Some(seed);
+        g
     }
 
     #[cfg(feature = "wrap_walls")]
-    pub fn new<R: RngLike>(grid: GridSize, rng: R) -> Self {
-        Self::new_with_wrap(grid, rng, false)
+    pub fn new_with_wrap<R: RngLike>(grid: GridSize, rng: R, wrap_walls: bool) -> Self {
+        let wall_behavior = if wrap_walls { WallBehavior::Wrap } else { WallBehavior::Die };
+        Self::build(grid, rng, wall_behavior)
     }
 
-    #[cfg(all(feature = "wrap_walls", not(feature = "multiple_foods")))]
-    pub fn new_with_wrap<R: RngLike>(grid: GridSize, mut rng: R, wrap_walls: bool) -> Self {
-        let start = Position {
-            x: grid.w / 2,
-            y: grid.h / 2,
-        };
+    fn build<R: RngLike>(grid: GridSize, mut rng: R, wall_behavior: WallBehavior) -> Self {
+        let start = grid.center();
 
         let snake = Snake {
             body: std::iter::once(start).collect(),
             dir: Direction::Right,
         };
 
-        let food = spawn_food(&grid, &snake, &mut rng);
+        // A grid with no free cell keeps the food under the head; the first step ends the game
+        #[cfg(not(feature = "multiple_foods"))]
+        let food = spawn_food(&grid, &snake, &mut rng, &[], 0).unwrap_or(start);
+
+        #[cfg(feature = "multiple_foods")]
+        let foods =
+            spawn_initial_foods(&grid, &snake, &mut rng, &[], DEFAULT_MIN_FOODS, DEFAULT_MAX_FOODS, 0);
 
-        Self {
+        let mut g = Self {
             grid,
             snake,
+            #[cfg(not(feature = "multiple_foods"))]
             food,
+            #[cfg(feature = "multiple_foods")]
+            foods,
+            #[cfg(feature = "multiple_foods")]
+            min_foods: DEFAULT_MIN_FOODS,
+            #[cfg(feature = "multiple_foods")]
+            max_foods: DEFAULT_MAX_FOODS,
+            #[cfg(feature = "multiple_foods")]
+            streak_type: None,
+            #[cfg(feature = "multiple_foods")]
+            streak_len: 0,
+            #[cfg(feature = "multiple_foods")]
+            golden_rush_until: None,
+            #[cfg(feature = "multiple_foods")]
+            pending_growth: 0,
             score: 0,
+            #[cfg(feature = "signed_score")]
+            score_i: 0,
             run_state: RunState::Running,
-            wrap_walls,
+            wall_behavior,
+            #[cfg(feature = "wrap_walls")]
+            wrap_cost: None,
+            #[cfg(feature = "wrap_walls")]
+            last_wrap: None,
+            allow_reversal: false,
+            tick: 0,
+            lives: 0,
+            starting_lives: 0,
+            graze_penalty: None,
+            seed: None,
+            session_rng: derive_session_rng(&mut rng),
+            spawn_min_head_dist: 0,
+            roaming_food: None,
+            peaceful: false,
+            food_value_scale: false,
+            no_grow: false,
+            wait_for_input: false,
+            first_input_seen: false,
+            recent_eats: VecDeque::new(),
+            trail_cap: 0,
+            trail: VecDeque::new(),
+            bonus_zone: None,
+            last_dir: Direction::Right,
+            momentum_after: None,
+            straight_run: 0,
+            max_turns: None,
+            recent_turns: VecDeque::new(),
+            game_over_reason: None,
+            time_limit_ticks: None,
+            foods_remaining: None,
+            food_quota: None,
+            win_reason: None,
+            death_cell: None,
+            #[cfg(feature = "heatmap")]
+            spawn_heat: vec![0; grid.area()],
+        };
+        // The fallback food under the head (full grid) was never actually spawned
+        for p in g.food_positions() {
+            if !g.snake.body.contains(&p) {
+                g.record_spawn(p);
+            }
         }
+        g
     }
 
-    #[cfg(all(feature = "wrap_walls", feature = "multiple_foods"))]
-    pub fn new_with_wrap<R: RngLike>(grid: GridSize, mut rng: R, wrap_walls: bool) -> Self {
-        let start = Position {
-            x: grid.w / 2,
-            y: grid.h / 2,
-        };
+    /// Pause a running game; no-op when already paused or over
+    pub fn pause(&mut self) {
+        if matches!(self.run_state, RunState::Running) {
+            self.run_state = RunState::Paused;
+        }
+    }
 
-        let snake = Snake {
-            body: std::iter::once(start).collect(),
-            dir: Direction::Right,
-        };
+    /// Resume a paused game; no-op when running or over
+    pub fn resume(&mut self) {
+        if matches!(self.run_state, RunState::Paused) {
+            self.run_state = RunState::Running;
+        }
+    }
 
-        let foods = spawn_initial_foods(&grid, &snake, &mut rng);
+    pub fn is_paused(&self) -> bool {
+        matches!(self.run_state, RunState::Paused)
+    }
 
-        Self {
-            grid,
-            snake,
-            foods,
-            score: 0,
-            run_state: RunState::Running,
-            wrap_walls,
+    pub fn is_over(&self) -> bool {
+        matches!(self.run_state, RunState::Over)
+    }
+
+    pub fn is_won(&self) -> bool {
+        matches!(self.run_state, RunState::Won)
+    }
+
+    /// Whether the game has ended, lost (`Over`) or won
+    pub fn is_finished(&self) -> bool {
+        self.is_over() || self.is_won()
+    }
+
+    pub fn is_running(&self) -> bool {
+        matches!(self.run_state, RunState::Running)
+    }
+
+    /// Hold the snake still for `ticks` loop updates before running ("3..2..1")
+    pub fn start_countdown(&mut self, ticks: u8) {
+        if ticks > 0 {
+            self.run_state = RunState::Countdown(ticks);
         }
     }
 
-    #[cfg(all(not(feature = "wrap_walls"), not(feature = "multiple_foods")))]
-    fn new_with_wrap<R: RngLike>(grid: GridSize, mut rng: R, _wrap_walls: bool) -> Self {
-        let start = Position {
-            x: grid.w / 2,
-            y: grid.h / 2,
-        };
+    /// Spend one tick of a pending countdown, switching to `Running` when it runs out;
+    /// returns false (and does nothing) when no countdown is active
+    pub fn advance_countdown(&mut self) -> bool {
+        match self.run_state {
+            RunState::Countdown(n) if n > 1 => self.run_state = RunState::Countdown(n - 1),
+            RunState::Countdown(_) => self.run_state = RunState::Running,
+            _ => return false,
+        }
+        true
+    }
 
-        let snake = Snake {
-            body: std::iter::once(start).collect(),
-            dir: Direction::Right,
-        };
+    /// End the game from outside the rules (e.g. a frontend-detected quit or timeout)
+    pub fn set_over(&mut self) {
+        self.run_state = RunState::Over;
+    }
 
-        let food = spawn_food(&grid, &snake, &mut rng);
+    /// End the game because of a fatal collision
+    pub(crate) fn set_over_by(&mut self, reason: GameOverReason) {
+        self.run_state = RunState::Over;
+        self.game_over_reason = Some(reason);
+    }
 
-        Self {
-            grid,
-            snake,
-            food,
-            score: 0,
-            run_state: RunState::Running,
+    /// Collision that ended the game; `None` while playing or when it ended another way
+    pub fn game_over_reason(&self) -> Option<GameOverReason> {
+        self.game_over_reason
+    }
+
+    /// How the game was won; `None` unless `run_state` is `Won`
+    pub fn win_reason(&self) -> Option<WinReason> {
+        self.win_reason
+    }
+
+    /// Win the game if it is still running and `tick` has reached `time_limit_ticks`;
+    /// returns whether it was won
+    pub fn check_time_limit(&mut self) -> bool {
+        let reached = self.time_limit_ticks.is_some_and(|limit| self.tick >= limit);
+        if reached && self.is_running() {
+            self.run_state = RunState::Won;
+            self.win_reason = Some(WinReason::TimeSurvived);
+            return true;
         }
+        false
     }
 
-    #[cfg(all(not(feature = "wrap_walls"), feature = "multiple_foods"))]
-    fn new_with_wrap<R: RngLike>(grid: GridSize, mut rng: R, _wrap_walls: bool) -> Self {
-        let start = Position {
-            x: grid.w / 2,
-            y: grid.h / 2,
+    /// Count one eaten food against `foods_remaining`, winning the game on the last one
+    pub(crate) fn use_food_quota(&mut self) {
+        let Some(left) = self.foods_remaining.as_mut() else {
+            return;
         };
+        *left = left.saturating_sub(1);
+        if *left == 0 && self.is_running() {
+            self.run_state = RunState::Won;
+            self.win_reason = Some(WinReason::QuotaCleared);
+        }
+    }
 
-        let snake = Snake {
-            body: std::iter::once(start).collect(),
-            dir: Direction::Right,
+    /// Number of successful moves since the game started (or was last reset)
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Point the snake in `dir`, ignoring 180-degree reversals unless `allow_reversal` is set
+    ///
+    /// Reversals are judged against `last_dir`, the direction actually moved, so several
+    /// calls between two steps can't turn the snake back into its neck. A single-segment
+    /// snake has nothing behind it, so it may always reverse.
+    ///
+    /// With `max_turns` set, a change from `last_dir` is also ignored once the turn budget
+    /// for the current window is spent.
+    pub fn apply_direction(&mut self, dir: Direction) {
+        let is_reversal = dir == self.last_dir.opposite() && self.snake.body.len() > 1;
+        if is_reversal && !self.allow_reversal {
+            return;
+        }
+        if dir != self.last_dir && self.turn_budget_spent() {
+            return;
+        }
+        self.snake.dir = dir;
+    }
+
+    /// Whether the next step changes direction from the last one
+    pub fn turned_this_step(&self) -> bool {
+        self.snake.dir != self.last_dir
+    }
+
+    /// Record the direction just moved in, remembering turns for `max_turns` and counting
+    /// the `straight_run`
+    pub(crate) fn record_move(&mut self) {
+        if self.turned_this_step() {
+            if let Some(limit) = self.max_turns {
+                while self.recent_turns.front().is_some_and(|&t| t + limit.window_ticks <= self.tick) {
+                    self.recent_turns.pop_front();
+                }
+                self.recent_turns.push_back(self.tick);
+            }
+            self.straight_run = 0;
+        }
+        self.straight_run += 1;
+        self.last_dir = self.snake.dir;
+    }
+
+    /// Whether momentum mode has the snake moving twice per update right now
+    pub fn has_momentum(&self) -> bool {
+        self.momentum_after.is_some_and(|k| self.straight_run >= k)
+    }
+
+    /// Whether `max_turns` allows no more turns in the current window
+    pub(crate) fn turn_budget_spent(&self) -> bool {
+        let Some(limit) = self.max_turns else {
+            return false;
         };
+        let in_window = self.recent_turns.iter().filter(|&&t| t + limit.window_ticks > self.tick).count();
+        in_window as u32 >= limit.turns
+    }
 
-        let foods = spawn_initial_foods(&grid, &snake, &mut rng);
+    /// Copy of the game for AI lookahead: identical gameplay, but display-only buffers
+    /// (`recent_eats`, the trail, the spawn heatmap) start empty instead of being copied
+    pub fn lightweight_clone(&self) -> GameState {
+        GameState {
+            grid: self.grid,
+            snake: self.snake.clone(),
+            #[cfg(not(feature = "multiple_foods"))]
+            food: self.food,
+            #[cfg(feature = "multiple_foods")]
+            foods: self.foods.clone(),
+            #[cfg(feature = "multiple_foods")]
+            min_foods: self.min_foods,
+            #[cfg(feature = "multiple_foods")]
+            max_foods: self.max_foods,
+            #[cfg(feature = "multiple_foods")]
+            streak_type: self.streak_type,
+            #[cfg(feature = "multiple_foods")]
+            streak_len: self.streak_len,
+            #[cfg(feature = "multiple_foods")]
+            golden_rush_until: self.golden_rush_until,
+            #[cfg(feature = "multiple_foods")]
+            pending_growth: self.pending_growth,
+            score: self.score,
+            #[cfg(feature = "signed_score")]
+            score_i: self.score_i,
+            run_state: self.run_state,
+            wall_behavior: self.wall_behavior,
+            #[cfg(feature = "wrap_walls")]
+            wrap_cost: self.wrap_cost,
+            #[cfg(feature = "wrap_walls")]
+            last_wrap: self.last_wrap,
+            allow_reversal: self.allow_reversal,
+            tick: self.tick,
+            graze_penalty: self.graze_penalty,
+            seed: self.seed,
+            session_rng: self.session_rng.clone(),
+            lives: self.lives,
+            starting_lives: self.starting_lives,
+            spawn_min_head_dist: self.spawn_min_head_dist,
+            roaming_food: self.roaming_food,
+            peaceful: self.peaceful,
+            food_value_scale: self.food_value_scale,
+            no_grow: self.no_grow,
+            wait_for_input: self.wait_for_input,
+            first_input_seen: self.first_input_seen,
+            recent_eats: VecDeque::new(),
+            trail_cap: self.trail_cap,
+            trail: VecDeque::new(),
+            bonus_zone: self.bonus_zone,
+            last_dir: self.last_dir,
+            momentum_after: self.momentum_after,
+            straight_run: self.straight_run,
+            max_turns: self.max_turns,
+            recent_turns: self.recent_turns.clone(),
+            game_over_reason: self.game_over_reason,
+            time_limit_ticks: self.time_limit_ticks,
+            foods_remaining: self.foods_remaining,
+            food_quota: self.food_quota,
+            win_reason: self.win_reason,
+            death_cell: self.death_cell,
+            #[cfg(feature = "heatmap")]
+            spawn_heat: Vec::new(),
+        }
+    }
 
-        Self {
-            grid,
-            snake,
-            foods,
-            score: 0,
-            run_state: RunState::Running,
+    /// Check that every snake segment and food is on the grid and that none share a cell
+    ///
+    /// Useful after hand-editing a board; a fresh or played board (roaming prey included)
+    /// always validates unless the grid is too small to hold any food.
+    pub fn validate_placement(&self) -> Result<(), PlacementError> {
+        let mut foods = self.food_positions();
+        foods.extend(self.roaming_food);
+        if let Some(&p) = self.snake.body.iter().chain(&foods).find(|&&p| out_of_bounds(p, self.grid)) {
+            return Err(PlacementError::OutOfBounds(p));
         }
+
+        let mut seen = std::collections::HashSet::new();
+        if let Some(&p) = self.snake.body.iter().find(|&&p| !seen.insert(p)) {
+            return Err(PlacementError::SnakeOverlap(p));
+        }
+        if let Some(&p) = foods.iter().find(|p| self.snake.body.contains(p)) {
+            return Err(PlacementError::FoodOnSnake(p));
+        }
+        seen.clear();
+        if let Some(&p) = foods.iter().find(|&&p| !seen.insert(p)) {
+            return Err(PlacementError::FoodOverlap(p));
+        }
+        Ok(())
     }
 
-    pub fn pause(&mut self) {
-        if matches!(self.run_state, RunState::Running) {
-            self.run_state = RunState::Paused;
+    /// Count a food spawn at `pos` in `spawn_heat` (no-op without the `heatmap` feature)
+    #[cfg_attr(not(feature = "heatmap"), allow(unused_variables))]
+    pub(crate) fn record_spawn(&mut self, pos: Position) {
+        #[cfg(feature = "heatmap")]
+        if let Some(count) = self.spawn_heat.get_mut((pos.y * self.grid.w + pos.x) as usize) {
+            *count += 1;
         }
     }
 
-    pub fn resume(&mut self) {
-        if matches!(self.run_state, RunState::Paused) {
-            self.run_state = RunState::Running;
+    /// Whether a golden food rush is on at the current `tick`
+    #[cfg(feature = "multiple_foods")]
+    pub fn in_golden_rush(&self) -> bool {
+        self.golden_rush_until.is_some_and(|until| self.tick < until)
+    }
+
+    /// Put a freshly spawned food on the board, turning it Golden during a rush
+    ///
+    /// The type is overridden after the spawn rolled it, so a rush never changes where
+    /// food lands for a given RNG.
+    #[cfg(feature = "multiple_foods")]
+    pub(crate) fn add_spawned_food(&mut self, mut food: Food) {
+        if self.in_golden_rush() {
+            food.food_type = FoodType::Golden;
         }
+        self.record_spawn(food.position);
+        self.foods.push(food);
     }
 
-    pub fn is_paused(&self) -> bool {
-        matches!(self.run_state, RunState::Paused)
+    /// Current score; negative only with the `signed_score` feature
+    pub fn display_score(&self) -> i64 {
+        #[cfg(feature = "signed_score")]
+        let score = self.score_i;
+        #[cfg(not(feature = "signed_score"))]
+        let score = self.score as i64;
+        score
     }
 
-    pub fn is_over(&self) -> bool {
-        matches!(self.run_state, RunState::Over)
+    /// Set the score, flooring at 0 unless the `signed_score` feature is on
+    pub fn set_score(&mut self, score: i64) {
+        #[cfg(feature = "signed_score")]
+        {
+            self.score_i = score;
+        }
+        self.score = score.clamp(0, u32::MAX as i64) as u32;
     }
 
-    #[cfg(not(feature = "multiple_foods"))]
-    pub fn reset<R: RngLike>(&mut self, mut rng: R) {
-        let start = Position {
-            x: self.grid.w / 2,
-            y: self.grid.h / 2,
-        };
+    pub(crate) fn add_score(&mut self, points: u32) {
+        self.set_score(self.display_score() + points as i64);
+    }
 
-        self.snake = Snake {
-            body: std::iter::once(start).collect(),
-            dir: Direction::Right,
+    /// Take `points` off the score (floored at 0 unless `signed_score` is on)
+    pub(crate) fn deduct_score(&mut self, points: u32) {
+        self.set_score(self.display_score() - points as i64);
+    }
+
+    /// Most recent head cells (up to `trail_cap`), oldest first
+    pub fn trail_cells(&self) -> &VecDeque<Position> {
+        &self.trail
+    }
+
+    /// Append the new head cell to the trail, dropping the oldest beyond `trail_cap`
+    pub(crate) fn record_trail(&mut self, head: Position) {
+        if self.trail_cap == 0 {
+            return;
+        }
+        while self.trail.len() >= self.trail_cap {
+            self.trail.pop_front();
+        }
+        self.trail.push_back(head);
+    }
+
+    /// Remember that food was eaten at `pos`, forgetting the oldest entry beyond the cap
+    pub(crate) fn record_eat(&mut self, pos: Position) {
+        if self.recent_eats.len() == RECENT_EATS_CAP {
+            self.recent_eats.pop_front();
+        }
+        self.recent_eats.push_back(pos);
+    }
+
+    /// Whether `pos` lies inside `bonus_zone`, borders included
+    pub fn in_bonus_zone(&self, pos: Position) -> bool {
+        self.bonus_zone.is_some_and(|(a, b)| {
+            (a.x.min(b.x)..=a.x.max(b.x)).contains(&pos.x) && (a.y.min(b.y)..=a.y.max(b.y)).contains(&pos.y)
+        })
+    }
+
+    /// Cells holding food, whichever food model is compiled in
+    pub fn food_positions(&self) -> Vec<Position> {
+        #[cfg(not(feature = "multiple_foods"))]
+        let positions = vec![self.food];
+        #[cfg(feature = "multiple_foods")]
+        let positions = self.foods.iter().map(|f| f.position).collect();
+        positions
+    }
+
+    /// Cells holding neither the snake, any food nor the roaming food, counted without
+    /// building the list of free cells
+    pub fn free_cell_count(&self) -> usize {
+        let off_snake = |p: &Position| !self.snake.body.contains(p);
+        #[cfg(not(feature = "multiple_foods"))]
+        let foods = usize::from(off_snake(&self.food));
+        #[cfg(feature = "multiple_foods")]
+        let foods = self.foods.iter().filter(|f| off_snake(&f.position)).count();
+        // Play keeps the prey off food, but a hand-edited board may not, so count it only
+        // on a cell nothing else covers
+        let prey = usize::from(
+            self.roaming_food.is_some_and(|p| off_snake(&p) && !self.food_positions().contains(&p)),
+        );
+        self.grid.area().saturating_sub(self.snake.body.len() + foods + prey)
+    }
+
+    /// Compare only observable state (grid, snake, food, score, run state), ignoring
+    /// counters such as `tick`
+    pub fn semantically_eq(&self, other: &GameState) -> bool {
+        #[cfg(not(feature = "multiple_foods"))]
+        let same_food = self.food == other.food;
+        #[cfg(feature = "multiple_foods")]
+        let same_food = self.foods == other.foods;
+
+        self.grid == other.grid
+            && self.snake == other.snake
+            && same_food
+            && self.score == other.score
+            && self.run_state == other.run_state
+    }
+
+    /// Describe the changes from `self` (earlier) to `other` (later)
+    pub fn diff(&self, other: &GameState) -> StateDiff {
+        let head_before = self.snake.body.front().copied();
+        let head_after = other.snake.body.front().copied();
+
+        #[cfg(not(feature = "multiple_foods"))]
+        let food_changed = self.food != other.food;
+        #[cfg(feature = "multiple_foods")]
+        let food_changed = self.foods != other.foods;
+
+        StateDiff {
+            head_moved: if head_after != head_before { head_after } else { None },
+            score_delta: other.display_score() - self.display_score(),
+            food_changed,
+            ended: other.is_finished() && !self.is_finished(),
+        }
+    }
+
+    /// One-line summary for logs and bug reports, e.g.
+    /// `20x20 len=3 score=12 state=Running head=(5,5) foods=4`
+    ///
+    /// Ends with `food=(x,y)` instead of a food count without `multiple_foods`; an empty
+    /// snake shows `head=none`.
+    pub fn describe(&self) -> String {
+        let head = match self.snake.body.front() {
+            Some(p) => format!("({},{})", p.x, p.y),
+            None => "none".to_string(),
         };
-        self.food = spawn_food(&self.grid, &self.snake, &mut rng);
-        self.score = 0;
-        self.run_state = RunState::Running;
-        // wrap_walls setting is preserved on reset
+        #[cfg(not(feature = "multiple_foods"))]
+        let food = format!("food=({},{})", self.food.x, self.food.y);
+        #[cfg(feature = "multiple_foods")]
+        let food = format!("foods={}", self.foods.len());
+        format!(
+            "{}x{} len={} score={} state={:?} head={} {}",
+            self.grid.w,
+            self.grid.h,
+            self.snake.body.len(),
+            self.display_score(),
+            self.run_state,
+            head,
+            food
+        )
     }
 
-    #[cfg(feature = "multiple_foods")]
+    /// Start a new round from the game's own RNG stream, so repeated calls give new boards
+    pub fn reset_continue(&mut self) {
+        let mut rng = self.session_rng.clone();
+        self.reset(&mut rng);
+    }
+
+    /// Start a new round from `Seeded::new(seed)`: the same board `from_seed` would build
+    pub fn reseed(&mut self, seed: u64) {
+        self.reset(Seeded::new(seed));
+        self.seed = Some(seed);
+    }
+
+    /// Start a new round with fresh food from `rng`
+    ///
+    /// Works in place: the snake's body buffer is cleared rather than reallocated. Settings
+    /// such as `wall_behavior` and food counts are preserved, `lives` and `foods_remaining`
+    /// are refilled from `starting_lives` and `food_quota`, and a roaming prey is released on
+    /// a fresh free cell. The round's seed is no longer known, so `seed` is cleared.
     pub fn reset<R: RngLike>(&mut self, mut rng: R) {
-        let start = Position {
-            x: self.grid.w / 2,
-            y: self.grid.h / 2,
-        };
+        let start = self.grid.center();
 
-        self.snake = Snake {
-            body: std::iter::once(start).collect(),
-            dir: Direction::Right,
-        };
-        self.foods = spawn_initial_foods(&self.grid, &self.snake, &mut rng);
-        self.score = 0;
+        self.snake.body.clear();
+        self.snake.body.push_back(start);
+        self.snake.dir = Direction::Right;
+        #[cfg(feature = "heatmap")]
+        self.spawn_heat.iter_mut().for_each(|count| *count = 0);
+        self.reset_food(&mut rng, start);
+        self.set_score(0);
         self.run_state = RunState::Running;
-        // wrap_walls setting is preserved on reset
+        self.tick = 0;
+        self.lives = self.starting_lives;
+        self.foods_remaining = self.food_quota;
+        self.last_dir = Direction::Right;
+        self.straight_run = 0;
+        self.recent_turns.clear();
+        self.game_over_reason = None;
+        self.win_reason = None;
+        self.death_cell = None;
+        self.recent_eats.clear();
+        self.trail.clear();
+        #[cfg(feature = "wrap_walls")]
+        {
+            self.last_wrap = None;
+        }
+        self.first_input_seen = false;
+        self.seed = None;
+        // A new round releases the prey somewhere free instead of carrying its old cell over
+        if self.roaming_food.is_some() {
+            self.roaming_food = crate::rules::random_free_cell(self, &mut rng);
+        }
+        self.session_rng = derive_session_rng(&mut rng);
+    }
+
+    #[cfg(not(feature = "multiple_foods"))]
+    fn reset_food<R: RngLike>(&mut self, rng: &mut R, start: Position) {
+        let prey = self.roaming_food.as_slice();
+        self.food = match spawn_food(&self.grid, &self.snake, rng, prey, self.spawn_min_head_dist) {
+            Some(food) => {
+                self.record_spawn(food);
+                food
+            }
+            None => start,
+        };
+    }
+
+    #[cfg(feature = "multiple_foods")]
+    fn reset_food<R: RngLike>(&mut self, rng: &mut R, _start: Position) {
+        self.foods.clear();
+        let (min, max, dist) = (self.min_foods, self.max_foods, self.spawn_min_head_dist);
+        let prey = self.roaming_food.as_slice();
+        self.foods.extend(spawn_initial_foods(&self.grid, &self.snake, rng, prey, min, max, dist));
+        for p in self.food_positions() {
+            self.record_spawn(p);
+        }
+        self.streak_type = None;
+        self.streak_len = 0;
+        self.pending_growth = 0;
+        self.golden_rush_until = None;
     }
 }
 
-fn spawn_food<R: RngLike>(grid: &GridSize, snake: &Snake, rng: &mut R) -> Position {
+/// Seed a private stream from the next draws of `rng`
+fn derive_session_rng<R: RngLike>(rng: &mut R) -> Seeded {
+    Seeded::new(((rng.next_u32() as u64) << 32) | rng.next_u32() as u64)
+}
+
+/// Whether at least one grid cell is covered by neither the snake nor `occupied`
+fn has_free_cell(grid: &GridSize, snake: &Snake, occupied: &[Position]) -> bool {
+    if snake.body.len() + occupied.len() < grid.area() {
+        return true;
+    }
+    (0..grid.w).any(|x| {
+        (0..grid.h).any(|y| {
+            let p = Position { x, y };
+            !snake.body.contains(&p) && !occupied.contains(&p)
+        })
+    })
+}
+
+/// `min_dist` if some free cell lies at least that far (Manhattan) from the head, else 0
+/// so that spawning never gets stuck
+fn head_clearance(grid: &GridSize, snake: &Snake, occupied: &[Position], min_dist: i32) -> i32 {
+    let Some(&head) = snake.body.front() else {
+        return 0;
+    };
+    if min_dist <= 0 {
+        return 0;
+    }
+    let satisfiable = (0..grid.w).any(|x| {
+        (0..grid.h).any(|y| {
+            let p = Position { x, y };
+            p.manhattan(head) >= min_dist && !snake.body.contains(&p) && !occupied.contains(&p)
+        })
+    });
+    if satisfiable { min_dist } else { 0 }
+}
+
+/// Sample an empty cell for food at least `min_head_dist` from the head (when possible),
+/// or `None` when the snake and `blocked` cells (such as the roaming prey) fill the grid
+#[cfg(not(feature = "multiple_foods"))]
+pub(crate) fn spawn_food<R: RngLike>(
+    grid: &GridSize,
+    snake: &Snake,
+    rng: &mut R,
+    blocked: &[Position],
+    min_head_dist: i32,
+) -> Option<Position> {
+    if !has_free_cell(grid, snake, blocked) {
+        return None;
+    }
+    let clearance = head_clearance(grid, snake, blocked, min_head_dist);
+    // sample until empty cell found (grid small → inexpensive; tests cover termination)
     loop {
         let x = (rng.next_u32() as i32).rem_euclid(grid.w);
         let y = (rng.next_u32() as i32).rem_euclid(grid.h);
         let p = Position { x, y };
 
-        if !snake.body.iter().any(|&s| s == p) {
-            return p;
+        if !snake.body.iter().any(|&s| s == p)
+            && !blocked.contains(&p)
+            && p.manhattan(snake.head()) >= clearance
+        {
+            return Some(p);
         }
     }
 }
 
 #[cfg(feature = "multiple_foods")]
-fn spawn_initial_foods<R: RngLike>(grid: &GridSize, snake: &Snake, rng: &mut R) -> Vec<Food> {
+fn spawn_initial_foods<R: RngLike>(
+    grid: &GridSize,
+    snake: &Snake,
+    rng: &mut R,
+    blocked: &[Position],
+    min: usize,
+    max: usize,
+    min_head_dist: i32,
+) -> Vec<Food> {
     let mut foods = Vec::new();
     
-    // Spawn 3-5 foods initially, with a mix of types
-    let num_foods = 3 + ((rng.next_u32() % 3) as usize); // 3-5 foods
+    // Spawn min..=max foods initially (3-5 by default), with a mix of types
+    let span = (max.saturating_sub(min) + 1) as u32;
+    let num_foods = min + ((rng.next_u32() % span) as usize);
     
+    #[cfg(not(feature = "cluster_food"))]
     for _ in 0..num_foods {
-        let food = spawn_food_with_type(grid, snake, rng, &foods);
-        foods.push(food);
+        match spawn_food_with_type(grid, snake, rng, &foods, blocked, min_head_dist) {
+            Some(food) => foods.push(food),
+            None => break,
+        }
+    }
+
+    #[cfg(feature = "cluster_food")]
+    while foods.len() < num_foods {
+        let size = CLUSTER_SIZE.min(num_foods - foods.len());
+        let cluster =
+            spawn_cluster_clear_of_head(grid, snake, rng, &foods, blocked, size, min_head_dist);
+        if cluster.is_empty() {
+            break;
+        }
+        foods.extend(cluster);
     }
     
     foods
 }
 
+/// Foods per cluster when `cluster_food` spawns them in groups
+#[cfg(feature = "cluster_food")]
+pub const CLUSTER_SIZE: usize = 3;
+
+/// Place up to `size` foods on a connected blob of free cells: a random free cell, then
+/// random free neighbors of the blob so far
+///
+/// Cells under the snake or `existing` foods are never used. The blob comes out smaller
+/// (possibly empty) when it runs out of free neighbors or the grid is full.
+#[cfg(feature = "cluster_food")]
+pub fn spawn_cluster<R: RngLike>(
+    grid: &GridSize,
+    snake: &Snake,
+    rng: &mut R,
+    existing: &[Food],
+    size: usize,
+) -> Vec<Food> {
+    spawn_cluster_clear_of_head(grid, snake, rng, existing, &[], size, 0)
+}
+
+/// `spawn_cluster` keeping every cell at least `min_head_dist` from the head (when possible)
+/// and off the `blocked` cells
+#[cfg(feature = "cluster_food")]
+pub(crate) fn spawn_cluster_clear_of_head<R: RngLike>(
+    grid: &GridSize,
+    snake: &Snake,
+    rng: &mut R,
+    existing: &[Food],
+    blocked: &[Position],
+    size: usize,
+    min_head_dist: i32,
+) -> Vec<Food> {
+    if size == 0 {
+        return Vec::new();
+    }
+    let Some(seed) = spawn_food_with_type(grid, snake, rng, existing, blocked, min_head_dist) else {
+        return Vec::new();
+    };
+    let occupied: Vec<Position> =
+        existing.iter().map(|f| f.position).chain(blocked.iter().copied()).collect();
+    let clearance = head_clearance(grid, snake, &occupied, min_head_dist);
+    let mut cluster = vec![seed];
+
+    while cluster.len() < size {
+        let mut frontier: Vec<Position> = Vec::new();
+        for food in &cluster {
+            for dir in [Direction::Up, Direction::Down, Direction::Left, Direction::Right] {
+                let p = food.position.step(dir);
+                if !out_of_bounds(p, *grid)
+                    && p.manhattan(snake.head()) >= clearance
+                    && !frontier.contains(&p)
+                    && !snake.body.contains(&p)
+                    && !occupied.contains(&p)
+                    && !cluster.iter().any(|f| f.position == p)
+                {
+                    frontier.push(p);
+                }
+            }
+        }
+        if frontier.is_empty() {
+            break;
+        }
+        let position = frontier[rng.next_range(frontier.len() as u32) as usize];
+        cluster.push(Food {
+            position,
+            food_type: determine_food_type(rng),
+            growth: 1,
+            hits_remaining: 1,
+        });
+    }
+    cluster
+}
+
+/// Sample a typed food on an empty cell at least `min_head_dist` from the head (when
+/// possible), or `None` when no cell is free; `blocked` cells (such as the roaming prey)
+/// count as taken
 #[cfg(feature = "multiple_foods")]
-fn spawn_food_with_type<R: RngLike>(
+pub(crate) fn spawn_food_with_type<R: RngLike>(
     grid: &GridSize,
     snake: &Snake,
     rng: &mut R,
     existing_foods: &[Food],
-) -> Food {
+    blocked: &[Position],
+    min_head_dist: i32,
+) -> Option<Food> {
+    let occupied: Vec<Position> =
+        existing_foods.iter().map(|f| f.position).chain(blocked.iter().copied()).collect();
+    if !has_free_cell(grid, snake, &occupied) {
+        return None;
+    }
+    let clearance = head_clearance(grid, snake, &occupied, min_head_dist);
     let food_type = determine_food_type(rng);
     
     loop {
@@ -231,30 +1071,23 @@ fn spawn_food_with_type<R: RngLike>(
         let y = (rng.next_u32() as i32).rem_euclid(grid.h);
         let p = Position { x, y };
 
-        // Check not on snake and not on existing foods
+        // Check not on snake, existing foods or blocked cells
         if !snake.body.iter().any(|&s| s == p)
-            && !existing_foods.iter().any(|f| f.position == p)
+            && !occupied.contains(&p)
+            && p.manhattan(snake.head()) >= clearance
         {
-            return Food {
+            return Some(Food {
                 position: p,
                 food_type,
-            };
+                growth: 1,
+                hits_remaining: 1,
+            });
         }
     }
 }
 
 #[cfg(feature = "multiple_foods")]
 fn determine_food_type<R: RngLike>(rng: &mut R) -> FoodType {
-    // Spawn probabilities:
-    // Normal: 70% (0-69)
-    // Golden: 25% (70-94)
-    // Special: 5% (95-99)
-    let roll = rng.next_u32() % 100;
-    if roll < 70 {
-        FoodType::Normal
-    } else if roll < 95 {
-        FoodType::Golden
-    } else {
-        FoodType::Special
-    }
+    const TYPES: [FoodType; 3] = [FoodType::Normal, FoodType::Golden, FoodType::Special];
+    TYPES[rng.weighted_index(&FOOD_WEIGHTS)]
 }
@@ -3,11 +3,22 @@
 //! Frontends implement `Input` and `Time` traits to drive the game loop.
 //! Tests can plug in mocks for deterministic sequences.
 
-use crate::{rng::RngLike, state::GameState, types::*};
+use crate::{
+    rng::RngLike,
+    state::{GameOverReason, GameState},
+    types::*,
+};
+use std::time::{Duration, Instant};
 
 /// Input port: provides the current desired direction for the snake
 pub trait Input {
     fn current_dir(&self) -> Direction;
+
+    /// Whether the player has pressed a direction since the last update; inputs without
+    /// discrete presses (scripts, AI) always count as having input
+    fn has_input(&self) -> bool {
+        true
+    }
 }
 
 /// Time port: tracks game ticks
@@ -15,18 +26,241 @@ pub trait Time {
     fn tick(&mut self) -> Tick;
 }
 
+/// Effects port: sound and visual cues for game events; every method defaults to a no-op
+pub trait Effects {
+    /// Food of type `food` was eaten (once per food, armored food only on its last hit)
+    fn on_eat(&mut self, _food: FoodType) {}
+
+    /// A collision ended the game (not called when a life absorbs it)
+    fn on_death(&mut self, _reason: GameOverReason) {}
+}
+
+/// `Effects` that ignores every event
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoEffects;
+
+impl Effects for NoEffects {}
+
+/// Source of the current instant, so `RealTime` can be driven by a fake clock in tests
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+/// The system's monotonic clock
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Wall-clock `Time`: a tick is `step` of real time, and `tick()` reports how many whole
+/// steps have elapsed since construction
+pub struct RealTime<C: Clock = SystemClock> {
+    clock: C,
+    start: Instant,
+    step: Duration,
+    ticks: u64,
+}
+
+impl RealTime {
+    pub fn new(step: Duration) -> Self {
+        Self::with_clock(SystemClock, step)
+    }
+}
+
+impl<C: Clock> RealTime<C> {
+    /// Start counting now on `clock`; a zero `step` is raised to one nanosecond
+    pub fn with_clock(clock: C, step: Duration) -> Self {
+        let start = clock.now();
+        let step = step.max(Duration::from_nanos(1));
+        Self { clock, start, step, ticks: 0 }
+    }
+
+    fn elapsed_steps(&self) -> u64 {
+        (self.clock.now().duration_since(self.start).as_nanos() / self.step.as_nanos()) as u64
+    }
+
+    /// Steps elapsed since the last `tick()`; steps missed while nobody ticked are not replayed
+    pub fn pending(&self) -> u64 {
+        self.elapsed_steps().saturating_sub(self.ticks)
+    }
+}
+
+impl<C: Clock> Time for RealTime<C> {
+    fn tick(&mut self) -> Tick {
+        self.ticks = self.elapsed_steps();
+        Tick(self.ticks)
+    }
+}
+
 /// Game loop that combines Input, Time, and RNG to update game state
 pub struct Loop<S: Input, T: Time, R: RngLike> {
     pub input: S,
     pub time: T,
     pub rng: R,
+    /// Domain steps applied per `update` (1 for normal play, more for turbo/debug)
+    pub steps_per_update: u32,
+    /// Called after every domain step (sound cues, analytics); see `on_step`
+    pub on_step: Option<StepHook>,
 }
 
+/// Callback run with the state after each domain step
+pub type StepHook = Box<dyn FnMut(&GameState)>;
+
 impl<S: Input, T: Time, R: RngLike> Loop<S, T, R> {
+    /// Loop running one domain step per update
+    pub fn new(input: S, time: T, rng: R) -> Self {
+        Self { input, time, rng, steps_per_update: 1, on_step: None }
+    }
+
+    /// Same loop driven by a different RNG
+    pub fn with_rng<R2: RngLike>(self, rng: R2) -> Loop<S, T, R2> {
+        Loop { input: self.input, time: self.time, rng, steps_per_update: self.steps_per_update, on_step: self.on_step }
+    }
+
+    /// Run `f` after each domain step, replacing any earlier hook
+    pub fn on_step(&mut self, f: impl FnMut(&GameState) + 'static) {
+        self.on_step = Some(Box::new(f));
+    }
+
+    pub fn with_steps_per_update(mut self, steps_per_update: u32) -> Self {
+        self.steps_per_update = steps_per_update;
+        self
+    }
+
     /// Update the game state based on current input, time, and RNG
+    ///
+    /// Moves any roaming food, then runs `steps_per_update` domain steps, stopping early
+    /// once the game is over or won on `time_limit_ticks`. Each of those becomes two steps
+    /// while the snake has momentum (see `GameState::momentum_after`). An update during a
+    /// countdown only counts it down, and with `wait_for_input` nothing moves until the
+    /// first input arrives.
     pub fn update(&mut self, g: &mut GameState) {
-        g.snake.dir = self.input.current_dir();
-        crate::rules::step(g, &mut self.rng);
+        self.update_with_effects(g, None);
+    }
+
+    /// Run `update`s while another one fits before `deadline`, counting each as taking at
+    /// least `step`; returns how many ran. Stops early once the game is over or won.
+    pub fn update_until(&mut self, g: &mut GameState, deadline: Instant, step: Duration) -> usize {
+        self.update_until_with_clock(g, &SystemClock, deadline, step)
+    }
+
+    /// `update_until` reading the time from `clock`
+    ///
+    /// A budgeted tick only ends once both `step` has been charged for it and `clock`
+    /// shows the real time spent, so a frozen clock still runs a bounded number of ticks.
+    pub fn update_until_with_clock<C: Clock>(
+        &mut self,
+        g: &mut GameState,
+        clock: &C,
+        deadline: Instant,
+        step: Duration,
+    ) -> usize {
+        let mut spent_until = clock.now();
+        let mut ticks = 0;
+        while !g.is_finished() && spent_until + step <= deadline {
+            self.update(g);
+            ticks += 1;
+            spent_until = (spent_until + step).max(clock.now());
+        }
+        ticks
+    }
+
+    /// `update`, reporting eaten food and deaths from each domain step to `effects`
+    pub fn update_with_effects(&mut self, g: &mut GameState, mut effects: Option<&mut dyn Effects>) {
+        if g.wait_for_input && !g.first_input_seen {
+            if !self.input.has_input() {
+                let _ = self.time.tick();
+                return;
+            }
+            g.first_input_seen = true;
+        }
+        g.apply_direction(self.input.current_dir());
+        if g.advance_countdown() {
+            let _ = self.time.tick();
+            return;
+        }
+        crate::rules::advance_roaming_food(g, &mut self.rng);
+        for _ in 0..self.steps_per_update {
+            // A turn on the first of the two steps ends the momentum right away
+            let repeats = if g.has_momentum() { 2 } else { 1 };
+            for i in 0..repeats {
+                if g.is_finished() || (i > 0 && !g.has_momentum()) {
+                    break;
+                }
+                let before = effects.is_some().then(|| (g.tick, edible_foods(g)));
+                crate::rules::step(g, &mut self.rng);
+                g.check_time_limit();
+                if let (Some(fx), Some((tick, foods))) = (effects.as_mut(), before) {
+                    report_effects(&mut **fx, g, tick, &foods);
+                }
+                if let Some(hook) = &mut self.on_step {
+                    hook(g);
+                }
+            }
+        }
         let _ = self.time.tick();
     }
 }
+
+/// Foods the next step would eat if the head moved onto them, with their cells
+fn edible_foods(g: &GameState) -> Vec<(Position, FoodType)> {
+    #[cfg(not(feature = "multiple_foods"))]
+    let foods = vec![(g.food, FoodType::Normal)];
+    #[cfg(feature = "multiple_foods")]
+    let foods = g
+        .foods
+        .iter()
+        .filter(|f| f.hits_remaining <= 1)
+        .map(|f| (f.position, f.food_type))
+        .collect();
+    foods
+}
+
+/// Tell `effects` what the step from `tick_before` (with `foods_before` on the board) did
+fn report_effects(
+    effects: &mut dyn Effects,
+    g: &GameState,
+    tick_before: u64,
+    foods_before: &[(Position, FoodType)],
+) {
+    if g.tick > tick_before {
+        let head = g.snake.head();
+        for &(_, food) in foods_before.iter().filter(|(p, _)| *p == head) {
+            effects.on_eat(food);
+        }
+    }
+    if let (true, Some(reason)) = (g.is_over(), g.game_over_reason()) {
+        effects.on_death(reason);
+    }
+}
+
+/// Drives a `Loop` one tick per `next()`, yielding a snapshot of the state after each tick
+///
+/// The snapshot of the tick that ends the game (lost or won) is the last item; after that
+/// the iterator returns `None`. A paused game keeps yielding unchanged snapshots.
+pub struct GameRunner<S: Input, T: Time, R: RngLike> {
+    pub loop_: Loop<S, T, R>,
+    pub state: GameState,
+}
+
+impl<S: Input, T: Time, R: RngLike> GameRunner<S, T, R> {
+    pub fn new(loop_: Loop<S, T, R>, state: GameState) -> Self {
+        Self { loop_, state }
+    }
+}
+
+impl<S: Input, T: Time, R: RngLike> Iterator for GameRunner<S, T, R> {
+    type Item = GameState;
+
+    fn next(&mut self) -> Option<GameState> {
+        if self.state.is_finished() {
+            return None;
+        }
+        self.loop_.update(&mut self.state);
+        Some(self.state.clone())
+    }
+}
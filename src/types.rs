@@ -1,15 +1,85 @@
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Position {
     pub x: i32,
     pub y: i32,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+impl Position {
+    /// The neighboring cell one step in `dir` (may lie off the grid)
+    pub fn step(self, dir: Direction) -> Position {
+        let (dx, dy) = dir.delta();
+        Position { x: self.x + dx, y: self.y + dy }
+    }
+
+    /// Grid-step distance: |dx| + |dy|
+    pub fn manhattan(self, other: Position) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// King-move distance: max(|dx|, |dy|)
+    pub fn chebyshev(self, other: Position) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Manhattan distance on a grid whose edges wrap around, taking the shorter way on each axis
+    pub fn manhattan_wrapped(self, other: Position, grid: GridSize) -> i32 {
+        let axis = |a: i32, b: i32, size: i32| (a - b).rem_euclid(size).min((b - a).rem_euclid(size));
+        axis(self.x, other.x, grid.w) + axis(self.y, other.y, grid.h)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct GridSize {
     pub w: i32,
     pub h: i32,
 }
 
+/// Largest grid (in cells) `GridSize::try_new` accepts; bigger boards are impractical to draw
+pub const MAX_GRID_AREA: usize = 100_000;
+
+/// Why a grid size was rejected
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GridError {
+    InvalidWidth(i32),
+    InvalidHeight(i32),
+    TooLarge { area: usize, max: usize },
+}
+
+impl GridSize {
+    /// Validated grid with positive dimensions and at most `MAX_GRID_AREA` cells
+    pub fn try_new(w: i32, h: i32) -> Result<GridSize, GridError> {
+        Self::try_new_capped(w, h, MAX_GRID_AREA)
+    }
+
+    /// Like `try_new` with a custom cap on the number of cells
+    pub fn try_new_capped(w: i32, h: i32, max_area: usize) -> Result<GridSize, GridError> {
+        if w <= 0 {
+            return Err(GridError::InvalidWidth(w));
+        }
+        if h <= 0 {
+            return Err(GridError::InvalidHeight(h));
+        }
+        let grid = GridSize { w, h };
+        if grid.area() > max_area {
+            return Err(GridError::TooLarge { area: grid.area(), max: max_area });
+        }
+        Ok(grid)
+    }
+
+    /// Number of cells in the grid (non-positive dimensions count as zero)
+    pub fn area(&self) -> usize {
+        self.w.max(0) as usize * self.h.max(0) as usize
+    }
+
+    /// Center cell, rounding down on even dimensions (snake start position)
+    pub fn center(&self) -> Position {
+        Position {
+            x: self.w / 2,
+            y: self.h / 2,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[allow(dead_code)] // Will be used in systems module
 pub enum Direction {
@@ -19,11 +89,33 @@ pub enum Direction {
     Right,
 }
 
+impl Direction {
+    /// The direction pointing the other way (a 180-degree turn)
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    /// Unit step `(dx, dy)` in grid coordinates (y grows downward)
+    pub fn delta(self) -> (i32, i32) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[allow(dead_code)] // Will be used in systems module
 pub struct Tick(pub u64);
 
-#[cfg(feature = "multiple_foods")]
+/// Kind of food; without `multiple_foods` all food is `Normal`
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FoodType {
     Normal,
@@ -31,6 +123,11 @@ pub enum FoodType {
     Special,
 }
 
+/// Spawn weights of Normal, Golden and Special food, in that order; they sum to 100, so
+/// each is the type's chance in percent
+#[cfg(feature = "multiple_foods")]
+pub const FOOD_WEIGHTS: [u32; 3] = [70, 25, 5];
+
 #[cfg(feature = "multiple_foods")]
 impl FoodType {
     pub fn point_value(&self) -> u32 {
@@ -47,4 +144,9 @@ impl FoodType {
 pub struct Food {
     pub position: Position,
     pub food_type: FoodType,
+    /// Segments added when eaten (1 for classic growth)
+    pub growth: u32,
+    /// Head contacts still needed before it is eaten: 1 for ordinary food, more for
+    /// armored food, which loses one per contact and only scores on the last
+    pub hits_remaining: u32,
 }
@@ -0,0 +1,86 @@
+#[cfg(feature = "cluster_food")]
+use snake_game::{
+    rng::Seeded,
+    state::{spawn_cluster, Snake},
+    types::*,
+};
+
+/// Whether every cell of `cells` can be reached from the first through neighbors in `cells`
+#[cfg(feature = "cluster_food")]
+fn connected(cells: &[Position]) -> bool {
+    let mut reached = vec![cells[0]];
+    let mut i = 0;
+    while i < reached.len() {
+        let p = reached[i];
+        for &q in cells {
+            if p.manhattan(q) == 1 && !reached.contains(&q) {
+                reached.push(q);
+            }
+        }
+        i += 1;
+    }
+    reached.len() == cells.len()
+}
+
+#[cfg(feature = "cluster_food")]
+#[test]
+fn cluster_of_three_is_a_connected_blob_of_free_cells() {
+    let grid = GridSize { w: 10, h: 10 };
+    let snake = Snake {
+        body: [Position { x: 5, y: 5 }, Position { x: 4, y: 5 }].into_iter().collect(),
+        dir: Direction::Right,
+    };
+    let mut rng = Seeded::new(7);
+
+    let cluster = spawn_cluster(&grid, &snake, &mut rng, &[], 3);
+    let cells: Vec<Position> = cluster.iter().map(|f| f.position).collect();
+
+    assert_eq!(cells.len(), 3);
+    for (i, &p) in cells.iter().enumerate() {
+        assert!(p.x >= 0 && p.y >= 0 && p.x < grid.w && p.y < grid.h);
+        assert!(!snake.body.contains(&p), "{p:?} is on the snake");
+        assert!(!cells[..i].contains(&p), "{p:?} appears twice");
+        assert!(cells.iter().any(|&q| p.manhattan(q) == 1), "{p:?} has no neighbor in the cluster");
+    }
+    assert!(connected(&cells));
+}
+
+#[cfg(feature = "cluster_food")]
+#[test]
+fn clusters_never_overlap_the_snake_or_each_other() {
+    let grid = GridSize { w: 8, h: 8 };
+    let snake = Snake {
+        body: (1..7).map(|x| Position { x, y: 3 }).collect(),
+        dir: Direction::Right,
+    };
+    let mut rng = Seeded::new(99);
+
+    for _ in 0..50 {
+        let first = spawn_cluster(&grid, &snake, &mut rng, &[], 3);
+        let second = spawn_cluster(&grid, &snake, &mut rng, &first, 3);
+        for food in first.iter().chain(&second) {
+            assert!(!snake.body.contains(&food.position), "{:?} is on the snake", food.position);
+        }
+        for food in &second {
+            assert!(first.iter().all(|f| f.position != food.position), "{:?} overlaps", food.position);
+        }
+    }
+}
+
+#[cfg(feature = "cluster_food")]
+#[test]
+fn cluster_shrinks_when_boxed_in() {
+    // A single free cell at (0, 0); the rest of the 2x2 grid is snake
+    let grid = GridSize { w: 2, h: 2 };
+    let snake = Snake {
+        body: [Position { x: 1, y: 0 }, Position { x: 1, y: 1 }, Position { x: 0, y: 1 }]
+            .into_iter()
+            .collect(),
+        dir: Direction::Up,
+    };
+    let mut rng = Seeded::new(1);
+
+    let cluster = spawn_cluster(&grid, &snake, &mut rng, &[], 3);
+    assert_eq!(cluster.len(), 1);
+    assert_eq!(cluster[0].position, Position { x: 0, y: 0 });
+}
@@ -0,0 +1,31 @@
+//! Engine-only tests: nothing here touches the `gui` feature (egui/eframe),
+//! so they also run under `cargo test --no-default-features`.
+
+use snake_game::{rng::Seeded, rules, state::GameState, types::*};
+
+#[test]
+fn test_default_game_is_20x20_and_steps() {
+    let mut g = GameState::default();
+    assert_eq!(g.grid, GridSize { w: 20, h: 20 });
+    assert_eq!(g.snake.head(), Position { x: 10, y: 10 });
+
+    rules::step(&mut g, &mut Seeded::new(0));
+
+    assert_eq!(g.snake.head(), Position { x: 11, y: 10 });
+    assert!(!g.is_over());
+}
+
+#[test]
+fn test_default_game_is_deterministic() {
+    assert_eq!(GameState::default(), GameState::default());
+}
+
+#[test]
+fn test_zero_seed_is_not_degenerate() {
+    use snake_game::rng::RngLike;
+
+    let mut rng = Seeded::new(0);
+    let values: Vec<u32> = (0..4).map(|_| rng.next_u32()).collect();
+    assert!(values.iter().any(|&v| v != 0));
+    assert_ne!(values[0], values[1]);
+}
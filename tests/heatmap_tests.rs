@@ -0,0 +1,65 @@
+#[cfg(feature = "heatmap")]
+use snake_game::{rng::Seeded, state::GameState, types::*};
+
+/// Put the only food at `pos`
+#[cfg(feature = "heatmap")]
+fn place_food(g: &mut GameState, pos: Position) {
+    #[cfg(not(feature = "multiple_foods"))]
+    {
+        g.food = pos;
+    }
+    #[cfg(feature = "multiple_foods")]
+    {
+        g.foods.truncate(1);
+        g.foods[0].position = pos;
+    }
+}
+
+/// Game that respawns exactly one food per meal and never grows, with no spawns counted yet
+#[cfg(feature = "heatmap")]
+fn feeding_game(grid: GridSize) -> GameState {
+    let mut g = GameState::new(grid, Seeded::new(9));
+    #[cfg(feature = "multiple_foods")]
+    {
+        g.min_foods = 1;
+        g.max_foods = 1;
+    }
+    g.no_grow = true;
+    g.spawn_heat.iter_mut().for_each(|count| *count = 0);
+    g
+}
+
+#[cfg(feature = "heatmap")]
+#[test]
+fn test_spawn_heat_counts_every_spawn() {
+    let grid = GridSize { w: 8, h: 6 };
+    let mut g = feeding_game(grid);
+    let mut rng = Seeded::new(21);
+
+    for _ in 0..100 {
+        g.snake.body[0] = Position { x: 2, y: 2 };
+        g.snake.dir = Direction::Right;
+        place_food(&mut g, Position { x: 3, y: 2 });
+        snake_game::rules::step(&mut g, &mut rng);
+
+        // The replacement food is counted in its own cell
+        let food = g.food_positions()[0];
+        assert!(g.spawn_heat[(food.y * grid.w + food.x) as usize] > 0);
+    }
+
+    assert_eq!(g.spawn_heat.len(), grid.area());
+    assert_eq!(g.spawn_heat.iter().sum::<u32>(), 100);
+    assert_eq!(g.spawn_heat[(2 * grid.w + 3) as usize], 0, "never under the head");
+}
+
+#[cfg(feature = "heatmap")]
+#[test]
+fn test_spawn_heat_restarts_with_the_round() {
+    let mut g = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(9));
+    let initial: u32 = g.spawn_heat.iter().sum();
+    assert_eq!(initial as usize, g.food_positions().len(), "initial food counts as spawned");
+
+    g.spawn_heat[0] += 50;
+    g.reset(Seeded::new(4));
+    assert_eq!(g.spawn_heat.iter().sum::<u32>() as usize, g.food_positions().len());
+}
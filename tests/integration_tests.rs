@@ -1,10 +1,10 @@
 use snake_game::{
     rng::{RngLike, Seeded},
-    state::GameState,
+    state::{GameOverReason, GameState, PlacementError, RunState, Snake, WinReason},
     types::*,
 };
 
-use snake_game::systems::{Input, Time, Loop as GameLoop};
+use snake_game::systems::{Clock, Input, RealTime, Time, Loop as GameLoop};
 
 #[test]
 fn test_game_state_initialization() {
@@ -15,7 +15,7 @@ fn test_game_state_initialization() {
     // Check that snake starts in center
     assert_eq!(state.snake.body.len(), 1);
     let center = Position { x: 5, y: 5 };
-    assert_eq!(state.snake.body[0], center);
+    assert_eq!(state.snake.head(), center);
 
     // Check initial direction
     assert_eq!(state.snake.dir, Direction::Right);
@@ -28,9 +28,9 @@ fn test_game_state_initialization() {
 
     // Check that food is not on snake
     #[cfg(not(feature = "multiple_foods"))]
-    assert_ne!(state.food, state.snake.body[0]);
+    assert_ne!(state.food, state.snake.head());
     #[cfg(feature = "multiple_foods")]
-    assert!(!state.foods.iter().any(|f| f.position == state.snake.body[0]));
+    assert!(!state.foods.iter().any(|f| f.position == state.snake.head()));
 }
 
 #[test]
@@ -48,14 +48,14 @@ fn test_game_state_different_grid_sizes() {
         // Snake should start in center of grid
         let expected_x = grid.w / 2;
         let expected_y = grid.h / 2;
-        assert_eq!(state.snake.body[0].x, expected_x);
-        assert_eq!(state.snake.body[0].y, expected_y);
+        assert_eq!(state.snake.head().x, expected_x);
+        assert_eq!(state.snake.head().y, expected_y);
 
         // Food should not be on snake
         #[cfg(not(feature = "multiple_foods"))]
-        assert_ne!(state.food, state.snake.body[0]);
+        assert_ne!(state.food, state.snake.head());
         #[cfg(feature = "multiple_foods")]
-        assert!(!state.foods.iter().any(|f| f.position == state.snake.body[0]));
+        assert!(!state.foods.iter().any(|f| f.position == state.snake.head()));
     }
 }
 
@@ -70,6 +70,26 @@ fn test_seeded_rng_deterministic() {
     }
 }
 
+#[test]
+fn test_seed_from_str_is_stable_fnv1a() {
+    use snake_game::rng::seed_from_str;
+    // Published FNV-1a test vectors pin the value across runs and platforms
+    assert_eq!(seed_from_str(""), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(seed_from_str("a"), 0xaf63_dc4c_8601_ec8c);
+    assert_eq!(seed_from_str("river-otter-42"), seed_from_str("river-otter-42"));
+    assert_ne!(seed_from_str("river-otter-42"), seed_from_str("river-otter-43"));
+    assert_ne!(seed_from_str("ab"), seed_from_str("ba"));
+}
+
+#[test]
+fn test_passphrase_seed_reproduces_board() {
+    let grid = GridSize { w: 12, h: 12 };
+    let a = GameState::new(grid, Seeded::from_str_seed("river-otter-42"));
+    let b = GameState::new(grid, Seeded::from_str_seed("river-otter-42"));
+    assert_eq!(a, b);
+    assert_eq!(Seeded::from_str_seed("river-otter-42"), Seeded::new(snake_game::rng::seed_from_str("river-otter-42")));
+}
+
 #[test]
 fn test_different_seeds_produce_different_sequences() {
     let mut rng1 = Seeded::new(42);
@@ -105,6 +125,110 @@ fn test_modulo_bounds_for_food_spawn() {
     }
 }
 
+#[test]
+fn test_seeded_snapshot_restores_sequence() {
+    use snake_game::rng::RngSnapshot;
+
+    let mut rng = Seeded::new(2024);
+    for _ in 0..5 {
+        rng.next_u32();
+    }
+    let snapshot = rng.state();
+    let expected: Vec<u32> = (0..5).map(|_| rng.next_u32()).collect();
+
+    let mut restored = Seeded::from_state(snapshot);
+    let replayed: Vec<u32> = (0..5).map(|_| restored.next_u32()).collect();
+    assert_eq!(replayed, expected);
+}
+
+#[test]
+fn test_seeded_zero_state_restores_like_seed_zero() {
+    use snake_game::rng::RngSnapshot;
+
+    let mut restored = Seeded::from_state(0);
+    let mut seeded = Seeded::new(0);
+    let values: Vec<u32> = (0..5).map(|_| restored.next_u32()).collect();
+
+    assert!(values.iter().any(|&v| v != 0), "a zero state must not get stuck");
+    assert_eq!(values, (0..5).map(|_| seeded.next_u32()).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_next_range_stays_in_bounds() {
+    let mut rng = Seeded::new(31);
+    for n in [1, 2, 7, 100] {
+        for _ in 0..200 {
+            assert!(rng.next_range(n) < n);
+        }
+    }
+}
+
+#[test]
+fn test_next_bool_extremes_are_certain() {
+    let mut rng = Seeded::new(13);
+    for _ in 0..500 {
+        assert!(rng.next_bool(100));
+        assert!(!rng.next_bool(0));
+    }
+}
+
+#[test]
+fn test_weighted_index_skips_zero_weights() {
+    let mut rng = Seeded::new(14);
+    for _ in 0..500 {
+        assert_eq!(rng.weighted_index(&[0, 0, 1]), 2);
+    }
+}
+
+#[test]
+fn test_weighted_index_follows_weights() {
+    let mut rng = Seeded::new(15);
+    let mut counts = [0u32; 3];
+    for _ in 0..10_000 {
+        counts[rng.weighted_index(&[70, 25, 5])] += 1;
+    }
+    // Expected 7000 / 2500 / 500; allow a few percent of sampling noise
+    assert!((6700..7300).contains(&counts[0]), "{:?}", counts);
+    assert!((2200..2800).contains(&counts[1]), "{:?}", counts);
+    assert!((350..650).contains(&counts[2]), "{:?}", counts);
+}
+
+#[test]
+fn test_shuffle_is_deterministic_for_seed() {
+    let mut a: Vec<u32> = (0..20).collect();
+    let mut b = a.clone();
+    Seeded::new(77).shuffle(&mut a);
+    Seeded::new(77).shuffle(&mut b);
+    assert_eq!(a, b);
+    assert_ne!(a, (0..20).collect::<Vec<u32>>(), "a 20-element shuffle should move something");
+}
+
+#[test]
+fn test_shuffle_is_a_permutation() {
+    let original = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
+    let mut shuffled = original.clone();
+    Seeded::new(8).shuffle(&mut shuffled);
+
+    let mut sorted_original = original.clone();
+    sorted_original.sort();
+    shuffled.sort();
+    assert_eq!(shuffled, sorted_original);
+}
+
+#[test]
+fn test_shuffle_short_slices_are_noops() {
+    let mut rng = Seeded::new(5);
+    let before = rng.clone();
+
+    let mut empty: [u8; 0] = [];
+    rng.shuffle(&mut empty);
+    let mut single = [42];
+    rng.shuffle(&mut single);
+
+    assert_eq!(single, [42]);
+    assert_eq!(rng, before, "no randomness is consumed");
+}
+
 // ---- systems (Loop) integration tests ----
 
 struct ScriptedInput {
@@ -148,17 +272,17 @@ impl Time for MockTime {
 fn test_loop_updates_game_state() {
     let grid = GridSize { w: 10, h: 10 };
     let mut game_state = GameState::new(grid, Seeded::new(42));
-    let initial_head = game_state.snake.body[0];
+    let initial_head = game_state.snake.head();
 
     let input = ScriptedInput::new(vec![Direction::Right]);
     let time = MockTime::new();
     let rng = Seeded::new(123);
 
-    let mut loop_system: GameLoop<_, _, _> = GameLoop { input, time, rng };
+    let mut loop_system: GameLoop<_, _, _> = GameLoop { input, time, rng, steps_per_update: 1, on_step: None };
     loop_system.update(&mut game_state);
 
     assert_eq!(
-        game_state.snake.body[0],
+        game_state.snake.head(),
         Position { x: initial_head.x + 1, y: initial_head.y }
     );
     assert_eq!(loop_system.time.current(), 1);
@@ -172,15 +296,15 @@ fn test_loop_deterministic_sequence() {
     let input = ScriptedInput::new(directions);
     let time = MockTime::new();
     let rng = Seeded::new(100);
-    let mut loop_system: GameLoop<_, _, _> = GameLoop { input, time, rng };
-    let initial_head = game_state.snake.body[0];
+    let mut loop_system: GameLoop<_, _, _> = GameLoop { input, time, rng, steps_per_update: 1, on_step: None };
+    let initial_head = game_state.snake.head();
     for i in 0..4 {
         loop_system.update(&mut game_state);
         loop_system.input.advance();
         assert_eq!(loop_system.time.current(), (i + 1) as u64);
     }
     assert_eq!(
-        game_state.snake.body[0],
+        game_state.snake.head(),
         Position { x: initial_head.x + 2, y: initial_head.y + 2 }
     );
 }
@@ -197,24 +321,170 @@ fn test_mock_time_advances() {
     assert_eq!(time.current(), 2);
 }
 
+/// Clock that only moves when the test advances it
+struct FakeClock(std::rc::Rc<std::cell::Cell<std::time::Instant>>);
+
+impl Clock for FakeClock {
+    fn now(&self) -> std::time::Instant {
+        self.0.get()
+    }
+}
+
+#[test]
+fn test_update_until_runs_the_ticks_that_fit_the_budget() {
+    use std::time::{Duration, Instant};
+    let start = Instant::now();
+    let clock = FakeClock(std::rc::Rc::new(std::cell::Cell::new(start)));
+    let step = Duration::from_millis(10);
+    let mut game_state = GameState::new(GridSize { w: 20, h: 20 }, Seeded::new(42));
+    game_state.snake.body[0] = Position { x: 1, y: 1 };
+    let mut loop_system = GameLoop::new(ScriptedInput::new(vec![Direction::Right]), MockTime::new(), Seeded::new(7));
+
+    // Budget for three steps, with a little to spare but not enough for a fourth
+    let ran = loop_system.update_until_with_clock(&mut game_state, &clock, start + step * 3 + step / 2, step);
+
+    assert_eq!(ran, 3);
+    assert_eq!(loop_system.time.current(), 3);
+    assert_eq!(game_state.tick(), 3);
+}
+
+#[test]
+fn test_update_until_stops_when_the_game_ends() {
+    use std::time::{Duration, Instant};
+    let start = Instant::now();
+    let clock = FakeClock(std::rc::Rc::new(std::cell::Cell::new(start)));
+    let step = Duration::from_millis(10);
+    let mut game_state = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(42));
+    game_state.snake.body[0] = Position { x: 8, y: 1 };
+    let mut loop_system = GameLoop::new(ScriptedInput::new(vec![Direction::Right]), MockTime::new(), Seeded::new(7));
+
+    let ran = loop_system.update_until_with_clock(&mut game_state, &clock, start + step * 10, step);
+
+    // One move to the last column, then the fatal one
+    assert_eq!(ran, 2);
+    assert!(game_state.is_over());
+}
+
+#[test]
+fn test_real_time_counts_elapsed_steps() {
+    use std::time::{Duration, Instant};
+    let now = std::rc::Rc::new(std::cell::Cell::new(Instant::now()));
+    let mut time = RealTime::with_clock(FakeClock(now.clone()), Duration::from_millis(150));
+    let advance = |ms| now.set(now.get() + Duration::from_millis(ms));
+
+    assert_eq!(time.pending(), 0);
+    advance(149);
+    assert_eq!(time.pending(), 0);
+    advance(1);
+    assert_eq!(time.pending(), 1);
+    assert_eq!(time.tick(), Tick(1));
+    assert_eq!(time.pending(), 0);
+
+    advance(460);
+    assert_eq!(time.pending(), 3);
+    assert_eq!(time.tick(), Tick(4), "ticks count steps since construction");
+    assert_eq!(time.pending(), 0);
+}
+
+#[test]
+fn test_real_time_with_zero_step_does_not_divide_by_zero() {
+    use std::time::{Duration, Instant};
+    let now = std::rc::Rc::new(std::cell::Cell::new(Instant::now()));
+    let time = RealTime::with_clock(FakeClock(now.clone()), Duration::ZERO);
+
+    assert_eq!(time.pending(), 0);
+    now.set(now.get() + Duration::from_nanos(5));
+    assert_eq!(time.pending(), 5, "a zero step counts as the smallest step");
+}
+
 #[test]
 fn test_scripted_input_returns_directions() {
     let input = ScriptedInput::new(vec![Direction::Up, Direction::Down, Direction::Left]);
     assert_eq!(input.current_dir(), Direction::Up);
 }
 
+#[test]
+fn test_loop_new_runs_single_step_updates() {
+    let grid = GridSize { w: 10, h: 10 };
+    let mut game_state = GameState::new(grid, Seeded::new(42));
+    let start = game_state.snake.head();
+
+    let mut loop_system = GameLoop::new(ScriptedInput::new(vec![Direction::Down]), MockTime::new(), Seeded::new(3));
+    assert_eq!(loop_system.steps_per_update, 1);
+    loop_system.update(&mut game_state);
+
+    assert_eq!(game_state.snake.head(), Position { x: start.x, y: start.y + 1 });
+    assert_eq!(loop_system.time.current(), 1);
+}
+
+#[test]
+fn test_loop_with_rng_swaps_rng_and_keeps_config() {
+    let loop_system = GameLoop::new(ScriptedInput::new(vec![Direction::Right]), MockTime::new(), Seeded::new(3))
+        .with_steps_per_update(2);
+    let mut swapped = loop_system.with_rng(Lcg(11));
+
+    assert_eq!(swapped.steps_per_update, 2);
+    assert_eq!(swapped.rng.next_u32(), Lcg(11).next_u32());
+
+    let mut game_state = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(42));
+    let start = game_state.snake.head();
+    swapped.update(&mut game_state);
+    assert_eq!(game_state.snake.head(), Position { x: start.x + 2, y: start.y });
+}
+
+#[test]
+fn test_countdown_holds_snake_before_running() {
+    let grid = GridSize { w: 10, h: 10 };
+    let mut game_state = GameState::new(grid, Seeded::new(42));
+    game_state.start_countdown(3);
+    let start = game_state.snake.head();
+
+    let input = ScriptedInput::new(vec![Direction::Right]);
+    let mut loop_system: GameLoop<_, _, _> =
+        GameLoop { input, time: MockTime::new(), rng: Seeded::new(1), steps_per_update: 1, on_step: None };
+
+    for remaining in [2, 1, 0] {
+        loop_system.update(&mut game_state);
+        assert_eq!(game_state.snake.head(), start, "snake must wait out the countdown");
+        if remaining > 0 {
+            assert_eq!(game_state.run_state, RunState::Countdown(remaining));
+            assert!(!game_state.is_running());
+        }
+    }
+    assert!(game_state.is_running());
+    assert_eq!(loop_system.time.current(), 3);
+
+    loop_system.update(&mut game_state);
+    assert_eq!(game_state.snake.head(), Position { x: start.x + 1, y: start.y });
+}
+
+#[test]
+fn test_step_ignored_during_countdown() {
+    let mut game_state = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(42));
+    game_state.start_countdown(1);
+    let before = game_state.clone();
+
+    snake_game::rules::step(&mut game_state, &mut Seeded::new(0));
+    assert_eq!(game_state, before);
+
+    // A zero-length countdown starts nothing
+    let mut running = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(42));
+    running.start_countdown(0);
+    assert!(running.is_running());
+}
+
 #[test]
 fn test_paused_state_prevents_movement_via_loop() {
     let grid = GridSize { w: 10, h: 10 };
     let mut game_state = GameState::new(grid, Seeded::new(1));
-    let initial_head = game_state.snake.body[0];
+    let initial_head = game_state.snake.head();
     let input = ScriptedInput::new(vec![Direction::Right]);
     let time = MockTime::new();
     let rng = Seeded::new(2);
-    let mut loop_system: GameLoop<_, _, _> = GameLoop { input, time, rng };
+    let mut loop_system: GameLoop<_, _, _> = GameLoop { input, time, rng, steps_per_update: 1, on_step: None };
     game_state.pause();
     loop_system.update(&mut game_state);
-    assert_eq!(game_state.snake.body[0], initial_head);
+    assert_eq!(game_state.snake.head(), initial_head);
     assert!(game_state.is_paused());
 }
 
@@ -222,20 +492,206 @@ fn test_paused_state_prevents_movement_via_loop() {
 fn test_resume_allows_movement_again_via_loop() {
     let grid = GridSize { w: 10, h: 10 };
     let mut game_state = GameState::new(grid, Seeded::new(1));
-    let initial_head = game_state.snake.body[0];
+    let initial_head = game_state.snake.head();
     let input = ScriptedInput::new(vec![Direction::Right]);
     let time = MockTime::new();
     let rng = Seeded::new(2);
-    let mut loop_system: GameLoop<_, _, _> = GameLoop { input, time, rng };
+    let mut loop_system: GameLoop<_, _, _> = GameLoop { input, time, rng, steps_per_update: 1, on_step: None };
     game_state.pause();
     game_state.resume();
     loop_system.update(&mut game_state);
     assert_eq!(
-        game_state.snake.body[0],
+        game_state.snake.head(),
         Position { x: initial_head.x + 1, y: initial_head.y }
     );
 }
 
+/// Game on `grid` with the head at `head` and no food anywhere near the snake's path
+fn foodless_game(grid: GridSize, head: Position) -> GameState {
+    let mut game_state = GameState::new(grid, Seeded::new(42));
+    game_state.snake.body[0] = head;
+    #[cfg(not(feature = "multiple_foods"))]
+    {
+        game_state.food = Position { x: grid.w - 1, y: grid.h - 1 };
+    }
+    #[cfg(feature = "multiple_foods")]
+    game_state.foods.clear();
+    game_state
+}
+
+#[test]
+fn test_steps_per_update_moves_multiple_cells() {
+    let mut game_state = foodless_game(GridSize { w: 10, h: 10 }, Position { x: 1, y: 1 });
+    let input = ScriptedInput::new(vec![Direction::Right]);
+    let time = MockTime::new();
+    let rng = Seeded::new(7);
+    let mut loop_system: GameLoop<_, _, _> = GameLoop { input, time, rng, steps_per_update: 3, on_step: None };
+
+    loop_system.update(&mut game_state);
+
+    assert_eq!(game_state.snake.head(), Position { x: 4, y: 1 });
+    assert_eq!(loop_system.time.current(), 1, "one update is one tick");
+}
+
+#[test]
+fn test_steps_per_update_stops_at_wall_mid_burst() {
+    let mut game_state = foodless_game(GridSize { w: 10, h: 10 }, Position { x: 8, y: 1 });
+    let input = ScriptedInput::new(vec![Direction::Right]);
+    let time = MockTime::new();
+    let rng = Seeded::new(7);
+    let mut loop_system: GameLoop<_, _, _> = GameLoop { input, time, rng, steps_per_update: 3, on_step: None };
+
+    loop_system.update(&mut game_state);
+
+    assert!(game_state.is_over());
+    assert_eq!(game_state.snake.head(), Position { x: 9, y: 1 });
+    assert_eq!(game_state.snake.body.len(), 1);
+    // Exactly one move was applied, and the loop still counts a single tick
+    assert_eq!(game_state.tick(), 1);
+    assert_eq!(loop_system.time.current(), 1);
+}
+
+#[test]
+fn test_momentum_double_steps_after_straight_run() {
+    let mut game_state = foodless_game(GridSize { w: 20, h: 20 }, Position { x: 1, y: 1 });
+    game_state.momentum_after = Some(3);
+    let mut loop_system = GameLoop::new(ScriptedInput::new(vec![Direction::Right]), MockTime::new(), Seeded::new(7));
+
+    for _ in 0..3 {
+        loop_system.update(&mut game_state);
+    }
+    assert_eq!(game_state.snake.head(), Position { x: 4, y: 1 });
+    assert_eq!(game_state.straight_run, 3);
+
+    // The fourth straight update moves two cells
+    loop_system.update(&mut game_state);
+    assert_eq!(game_state.snake.head(), Position { x: 6, y: 1 });
+    assert_eq!(game_state.tick(), 5);
+    assert_eq!(loop_system.time.current(), 4);
+}
+
+#[test]
+fn test_momentum_turn_resets_straight_run() {
+    let mut game_state = foodless_game(GridSize { w: 20, h: 20 }, Position { x: 1, y: 1 });
+    game_state.momentum_after = Some(3);
+    let input = ScriptedInput::new(vec![Direction::Right, Direction::Down]);
+    let mut loop_system = GameLoop::new(input, MockTime::new(), Seeded::new(7));
+    for _ in 0..4 {
+        loop_system.update(&mut game_state);
+    }
+    assert!(game_state.has_momentum());
+
+    loop_system.input.advance();
+    loop_system.update(&mut game_state);
+
+    assert_eq!(game_state.straight_run, 1);
+    assert!(!game_state.has_momentum());
+    let head = game_state.snake.head();
+    loop_system.update(&mut game_state);
+    assert_eq!(game_state.snake.head(), Position { x: head.x, y: head.y + 1 }, "back to single steps");
+}
+
+#[test]
+fn test_time_limit_wins_on_last_tick() {
+    let mut game_state = foodless_game(GridSize { w: 20, h: 20 }, Position { x: 1, y: 1 });
+    game_state.time_limit_ticks = Some(10);
+    let mut loop_system = GameLoop::new(ScriptedInput::new(vec![Direction::Right]), MockTime::new(), Seeded::new(7));
+
+    for _ in 0..9 {
+        loop_system.update(&mut game_state);
+    }
+    assert!(game_state.is_running());
+
+    loop_system.update(&mut game_state);
+    assert_eq!(game_state.run_state, RunState::Won);
+    assert_eq!(game_state.win_reason(), Some(WinReason::TimeSurvived));
+    assert!(game_state.is_finished() && !game_state.is_over());
+
+    // A won game stays put
+    loop_system.update(&mut game_state);
+    assert_eq!(game_state.tick(), 10);
+    assert_eq!(game_state.snake.head(), Position { x: 11, y: 1 });
+}
+
+#[test]
+fn test_dying_before_time_limit_is_a_loss() {
+    let mut game_state = foodless_game(GridSize { w: 20, h: 20 }, Position { x: 15, y: 1 });
+    game_state.time_limit_ticks = Some(10);
+    let mut loop_system = GameLoop::new(ScriptedInput::new(vec![Direction::Right]), MockTime::new(), Seeded::new(7));
+
+    for _ in 0..10 {
+        loop_system.update(&mut game_state);
+    }
+
+    assert_eq!(game_state.run_state, RunState::Over);
+    assert_eq!(game_state.win_reason(), None);
+    assert_eq!(game_state.game_over_reason(), Some(GameOverReason::Wall));
+}
+
+/// Effects that remembers every event it is told about
+#[derive(Default)]
+struct RecordingEffects {
+    eaten: Vec<FoodType>,
+    deaths: Vec<GameOverReason>,
+}
+
+impl snake_game::systems::Effects for RecordingEffects {
+    fn on_eat(&mut self, food: FoodType) {
+        self.eaten.push(food);
+    }
+
+    fn on_death(&mut self, reason: GameOverReason) {
+        self.deaths.push(reason);
+    }
+}
+
+#[test]
+fn test_effects_hear_eating_once_and_wall_death() {
+    let mut game_state = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(42));
+    game_state.snake.body[0] = Position { x: 6, y: 1 };
+    let ahead = Position { x: 7, y: 1 };
+    #[cfg(not(feature = "multiple_foods"))]
+    {
+        game_state.food = ahead;
+        game_state.spawn_min_head_dist = 3;
+    }
+    #[cfg(feature = "multiple_foods")]
+    {
+        game_state.min_foods = 0;
+        game_state.max_foods = 0;
+        game_state.foods = vec![Food { position: ahead, food_type: FoodType::Normal, growth: 1, hits_remaining: 1 }];
+    }
+    let mut loop_system = GameLoop::new(ScriptedInput::new(vec![Direction::Right]), MockTime::new(), Seeded::new(7));
+    let mut effects = RecordingEffects::default();
+
+    // Eat at x = 7, move to 8 and 9, then hit the wall
+    for _ in 0..4 {
+        loop_system.update_with_effects(&mut game_state, Some(&mut effects));
+    }
+
+    assert_eq!(effects.eaten, [FoodType::Normal]);
+    assert_eq!(effects.deaths, [GameOverReason::Wall]);
+    assert!(game_state.is_over());
+}
+
+#[test]
+fn test_game_runner_yields_until_death() {
+    use snake_game::systems::GameRunner;
+
+    let grid = GridSize { w: 10, h: 10 };
+    let state = GameState::new(grid, Seeded::new(42));
+    let input = ScriptedInput::new(vec![Direction::Right]);
+    let loop_system = GameLoop { input, time: MockTime::new(), rng: Seeded::new(5), steps_per_update: 1, on_step: None };
+
+    let states: Vec<GameState> = GameRunner::new(loop_system, state).collect();
+
+    // From x = 5 the snake reaches x = 9 in four ticks and hits the wall on the fifth
+    assert_eq!(states.len(), 5);
+    assert!(states.last().unwrap().is_over());
+    assert!(states[..4].iter().all(|s| !s.is_over()));
+    assert_eq!(states[3].snake.head(), Position { x: 9, y: 5 });
+}
+
 // ---- state reset invariants ----
 
 #[test]
@@ -245,7 +701,7 @@ fn test_reset_restores_invariants() {
     let mut state = GameState::new(grid, rng.clone());
     state.score = 5;
     state.snake.dir = Direction::Down;
-    let head = state.snake.body[0];
+    let head = state.snake.head();
     state.snake.body.push_back(Position { x: head.x - 1, y: head.y });
     // Mark over then reset
     // RunState is internal; black-box via public API resets invariants
@@ -255,9 +711,635 @@ fn test_reset_restores_invariants() {
     assert_eq!(state.score, 0);
     assert_eq!(state.snake.dir, Direction::Right);
     assert_eq!(state.snake.body.len(), 1);
-    assert_eq!(state.snake.body[0], center);
+    assert_eq!(state.snake.head(), center);
     #[cfg(not(feature = "multiple_foods"))]
     assert_ne!(state.food, center);
     #[cfg(feature = "multiple_foods")]
     assert!(!state.foods.iter().any(|f| f.position == center));
 }
+
+#[test]
+fn test_reset_reuses_body_capacity() {
+    let grid = GridSize { w: 12, h: 8 };
+    let mut state = GameState::new(grid, Seeded::new(99));
+    for x in 0..6 {
+        state.snake.body.push_back(Position { x, y: 0 });
+    }
+    let capacity = state.snake.body.capacity();
+
+    state.reset(Seeded::new(5));
+
+    assert_eq!(state.snake.body.len(), 1);
+    assert!(state.snake.body.capacity() >= capacity);
+}
+
+#[test]
+fn test_food_spawns_outside_head_exclusion_zone() {
+    let grid = GridSize { w: 7, h: 7 };
+    let mut state = GameState::new(grid, Seeded::new(1));
+    state.spawn_min_head_dist = 2;
+
+    for seed in 0..1000 {
+        state.reset(Seeded::new(seed));
+        let head = state.snake.head();
+        for food in state.food_positions() {
+            assert!(food.manhattan(head) >= 2, "seed {}: food {:?} too close to {:?}", seed, food, head);
+        }
+    }
+}
+
+#[test]
+fn test_impossible_exclusion_zone_falls_back() {
+    // Every free cell of a 3x1 grid is next to the head
+    let grid = GridSize { w: 3, h: 1 };
+    let mut state = GameState::new(grid, Seeded::new(1));
+    state.spawn_min_head_dist = 5;
+
+    state.reset(Seeded::new(9));
+
+    let layout = state.food_positions();
+    assert!(!layout.is_empty());
+    assert!(layout.iter().all(|&food| food != state.snake.head()));
+}
+
+#[test]
+fn test_reset_continue_advances_the_board() {
+    let grid = GridSize { w: 20, h: 20 };
+    let mut state = GameState::new(grid, Seeded::new(8));
+
+    state.reset_continue();
+    let first = state.food_positions();
+    state.reset_continue();
+    let second = state.food_positions();
+
+    assert_ne!(first, second, "consecutive rounds should not repeat the board");
+    assert_eq!(state.score, 0);
+    assert_eq!(state.snake.head(), grid.center());
+}
+
+#[test]
+fn test_reset_with_same_seed_reproduces_the_board() {
+    let grid = GridSize { w: 20, h: 20 };
+    let mut a = GameState::new(grid, Seeded::new(8));
+    let mut b = GameState::new(grid, Seeded::new(3));
+    a.reset_continue();
+
+    a.reset(Seeded::new(21));
+    b.reset(Seeded::new(21));
+    assert_eq!(a, b);
+
+    // Both now continue from the same stream as well
+    a.reset_continue();
+    b.reset_continue();
+    assert_eq!(a.food_positions(), b.food_positions());
+}
+
+#[test]
+fn test_semantically_eq_ignores_tick() {
+    let grid = GridSize { w: 10, h: 10 };
+    let a = GameState::new(grid, Seeded::new(6));
+    let mut b = a.clone();
+    b.tick = 17;
+
+    assert!(a.semantically_eq(&b));
+    assert_ne!(a, b);
+
+    b.score += 1;
+    assert!(!a.semantically_eq(&b));
+}
+
+// ---- state diff ----
+
+#[test]
+fn test_diff_of_identical_states_is_empty() {
+    let state = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(42));
+    assert_eq!(state.diff(&state.clone()), snake_game::state::StateDiff::default());
+}
+
+#[test]
+fn test_diff_after_plain_step() {
+    let grid = GridSize { w: 10, h: 10 };
+    let mut rng = Seeded::new(42);
+    let before = GameState::new(grid, rng.clone());
+    let mut after = before.clone();
+    #[cfg(not(feature = "multiple_foods"))]
+    assert_ne!(after.food, Position { x: 6, y: 5 }, "seed must not put food ahead");
+    #[cfg(feature = "multiple_foods")]
+    assert!(!after.foods.iter().any(|f| f.position == Position { x: 6, y: 5 }));
+
+    snake_game::rules::step(&mut after, &mut rng);
+
+    let diff = before.diff(&after);
+    assert_eq!(diff.head_moved, Some(Position { x: 6, y: 5 }));
+    assert_eq!(diff.score_delta, 0);
+    assert!(!diff.food_changed);
+    assert!(!diff.ended);
+}
+
+#[test]
+#[cfg(not(feature = "multiple_foods"))]
+fn test_diff_after_eating_reports_food_and_score() {
+    let grid = GridSize { w: 10, h: 10 };
+    let mut rng = Seeded::new(42);
+    let mut before = GameState::new(grid, rng.clone());
+    before.food = Position { x: 6, y: 5 };
+    let mut after = before.clone();
+
+    snake_game::rules::step(&mut after, &mut rng);
+
+    let diff = before.diff(&after);
+    assert_eq!(diff.head_moved, Some(Position { x: 6, y: 5 }));
+    assert_eq!(diff.score_delta, 1);
+    assert!(diff.food_changed);
+}
+
+#[test]
+fn test_diff_reports_game_ending() {
+    let grid = GridSize { w: 5, h: 5 };
+    let mut rng = Seeded::new(1);
+    let mut before = GameState::new(grid, rng.clone());
+    before.snake.body[0] = Position { x: 4, y: 2 };
+    let mut after = before.clone();
+
+    snake_game::rules::step(&mut after, &mut rng);
+
+    let diff = before.diff(&after);
+    assert!(diff.ended);
+    assert_eq!(diff.head_moved, None);
+}
+
+#[test]
+fn test_diff_reports_a_win_as_ending() {
+    let mut before = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(42));
+    before.time_limit_ticks = Some(1);
+    let mut after = before.clone();
+
+    snake_game::rules::step(&mut after, &mut Seeded::new(1));
+    assert!(after.check_time_limit());
+
+    assert!(before.diff(&after).ended);
+    assert!(!after.diff(&after.clone()).ended, "an already won game doesn't end again");
+}
+
+#[test]
+fn test_describe_summarizes_state_on_one_line() {
+    let mut g = GameState::new(GridSize { w: 20, h: 20 }, Seeded::new(1));
+    g.snake.body = [Position { x: 5, y: 5 }, Position { x: 4, y: 5 }, Position { x: 3, y: 5 }]
+        .into_iter()
+        .collect();
+    g.set_score(12);
+    #[cfg(not(feature = "multiple_foods"))]
+    {
+        g.food = Position { x: 9, y: 2 };
+        assert_eq!(g.describe(), "20x20 len=3 score=12 state=Running head=(5,5) food=(9,2)");
+    }
+    #[cfg(feature = "multiple_foods")]
+    {
+        g.foods.truncate(4);
+        let foods = g.foods.len();
+        assert_eq!(g.describe(), format!("20x20 len=3 score=12 state=Running head=(5,5) foods={foods}"));
+    }
+
+    g.pause();
+    g.snake.body.clear();
+    assert!(g.describe().contains("len=0 score=12 state=Paused head=none"));
+}
+
+#[test]
+fn test_free_cell_count_is_area_minus_occupied() {
+    let mut g = GameState::new(GridSize { w: 4, h: 4 }, Seeded::new(1));
+    g.snake.body = [Position { x: 2, y: 2 }, Position { x: 1, y: 2 }].into_iter().collect();
+    #[cfg(not(feature = "multiple_foods"))]
+    {
+        g.food = Position { x: 0, y: 0 };
+    }
+    #[cfg(feature = "multiple_foods")]
+    {
+        g.foods.truncate(1);
+        g.foods[0].position = Position { x: 0, y: 0 };
+    }
+    assert_eq!(g.free_cell_count(), 16 - 2 - 1);
+
+    g.roaming_food = Some(Position { x: 3, y: 3 });
+    assert_eq!(g.free_cell_count(), 16 - 2 - 1 - 1);
+
+    // Growing by a segment takes one more cell
+    g.snake.body.push_back(Position { x: 0, y: 2 });
+    assert_eq!(g.free_cell_count(), 16 - 3 - 1 - 1);
+
+    // A prey sharing the food's cell takes no extra cell
+    g.roaming_food = Some(Position { x: 0, y: 0 });
+    assert_eq!(g.free_cell_count(), 16 - 3 - 1);
+}
+
+#[test]
+fn test_free_cell_count_drops_as_snake_eats() {
+    let mut g = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(3));
+    let head = g.snake.head();
+    #[cfg(not(feature = "multiple_foods"))]
+    {
+        g.food = Position { x: head.x + 1, y: head.y };
+    }
+    #[cfg(feature = "multiple_foods")]
+    {
+        g.foods.truncate(1);
+        g.foods[0].position = Position { x: head.x + 1, y: head.y };
+        g.min_foods = 1;
+        g.max_foods = 1;
+    }
+    let before_eating = g.free_cell_count();
+
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+
+    assert_eq!(g.snake.body.len(), 2);
+    assert_eq!(g.free_cell_count(), before_eating - 1, "food respawned, snake grew by one");
+}
+
+// ---- grid center ----
+
+#[test]
+fn test_grid_center_even_and_odd_dimensions() {
+    assert_eq!(GridSize { w: 10, h: 10 }.center(), Position { x: 5, y: 5 });
+    assert_eq!(GridSize { w: 11, h: 11 }.center(), Position { x: 5, y: 5 });
+    assert_eq!(GridSize { w: 8, h: 13 }.center(), Position { x: 4, y: 6 });
+}
+
+/// Snake whose body follows `cells` from head to tail
+fn snake_along(cells: &[(i32, i32)]) -> Snake {
+    Snake {
+        body: cells.iter().map(|&(x, y)| Position { x, y }).collect(),
+        dir: Direction::Right,
+    }
+}
+
+#[test]
+fn test_single_segment_head_is_tail() {
+    let snake = snake_along(&[(3, 4)]);
+    assert_eq!(snake.head(), Position { x: 3, y: 4 });
+    assert_eq!(snake.tail(), Position { x: 3, y: 4 });
+}
+
+#[test]
+fn test_head_and_tail_are_body_ends() {
+    let snake = snake_along(&[(5, 5), (4, 5), (4, 6)]);
+    assert_eq!(snake.head(), Position { x: 5, y: 5 });
+    assert_eq!(snake.tail(), Position { x: 4, y: 6 });
+}
+
+#[test]
+fn test_contiguous_distinct_snake_is_valid() {
+    let grid = GridSize { w: 10, h: 10 };
+    assert!(snake_along(&[(3, 4)]).is_valid(grid));
+    assert!(snake_along(&[(5, 5), (4, 5), (4, 6), (4, 7)]).is_valid(grid));
+    assert!(snake_along(&[(0, 0), (1, 0), (1, 1), (0, 1)]).is_valid(grid), "head may touch the tail");
+}
+
+#[test]
+fn test_broken_snakes_are_invalid() {
+    let grid = GridSize { w: 10, h: 10 };
+    assert!(!snake_along(&[]).is_valid(grid), "empty body");
+    assert!(!snake_along(&[(2, 2), (2, 3), (3, 3), (3, 2), (2, 2)]).is_valid(grid), "duplicated cell");
+    assert!(!snake_along(&[(5, 5), (5, 5)]).is_valid(grid), "stacked segments");
+    assert!(!snake_along(&[(0, 0), (-1, 0)]).is_valid(grid), "out of bounds");
+    assert!(!snake_along(&[(9, 4), (10, 4)]).is_valid(grid), "out of bounds");
+    assert!(!snake_along(&[(5, 5), (4, 5), (2, 5)]).is_valid(grid), "gap");
+    assert!(!snake_along(&[(5, 5), (4, 6)]).is_valid(grid), "diagonal step");
+}
+
+#[test]
+fn test_square_loop_encloses_its_center() {
+    let grid = GridSize { w: 10, h: 10 };
+    // Ring around (3,3), head at (2,3) next to the tail at (2,4)
+    let snake = snake_along(&[(2, 3), (2, 2), (3, 2), (4, 2), (4, 3), (4, 4), (3, 4), (2, 4)]);
+    assert_eq!(snake.encloses(grid), Some(vec![Position { x: 3, y: 3 }]));
+}
+
+#[test]
+fn test_open_snakes_enclose_nothing() {
+    let grid = GridSize { w: 10, h: 10 };
+    let straight = snake_along(&[(5, 5), (4, 5), (3, 5), (2, 5)]);
+    assert_eq!(straight.encloses(grid), None);
+
+    // Head touches tail, but a 2x2 block has no interior
+    let block = snake_along(&[(1, 1), (2, 1), (2, 2), (1, 2)]);
+    assert_eq!(block.encloses(grid), None);
+
+    // A wall-to-wall line cuts off a corner, but it is not a loop
+    let wall = snake_along(&[(0, 2), (1, 2), (2, 2), (2, 1), (2, 0)]);
+    assert_eq!(wall.encloses(grid), None);
+}
+
+#[test]
+fn test_position_distances_straight_line() {
+    let a = Position { x: 2, y: 3 };
+    let b = Position { x: 7, y: 3 };
+    assert_eq!(a.manhattan(b), 5);
+    assert_eq!(a.chebyshev(b), 5);
+    assert_eq!(b.manhattan(a), 5);
+}
+
+#[test]
+fn test_position_distances_diagonal() {
+    let a = Position { x: 1, y: 1 };
+    let b = Position { x: 4, y: 5 };
+    assert_eq!(a.manhattan(b), 7);
+    assert_eq!(a.chebyshev(b), 4);
+    assert_eq!(a.manhattan(a), 0);
+}
+
+#[test]
+fn test_direction_deltas() {
+    assert_eq!(Direction::Up.delta(), (0, -1));
+    assert_eq!(Direction::Down.delta(), (0, 1));
+    assert_eq!(Direction::Left.delta(), (-1, 0));
+    assert_eq!(Direction::Right.delta(), (1, 0));
+}
+
+#[test]
+fn test_position_step_agrees_with_delta() {
+    let p = Position { x: 4, y: 7 };
+    for dir in [Direction::Up, Direction::Down, Direction::Left, Direction::Right] {
+        let (dx, dy) = dir.delta();
+        assert_eq!(p.step(dir), Position { x: p.x + dx, y: p.y + dy });
+        assert_eq!(p.step(dir).step(dir.opposite()), p);
+    }
+}
+
+#[test]
+fn test_position_manhattan_wrapped() {
+    let grid = GridSize { w: 10, h: 10 };
+    let left = Position { x: 0, y: 4 };
+    let right = Position { x: 9, y: 4 };
+    assert_eq!(left.manhattan(right), 9);
+    assert_eq!(left.manhattan_wrapped(right, grid), 1);
+
+    // Shorter way is taken independently on each axis
+    let corner = Position { x: 0, y: 0 };
+    assert_eq!(corner.manhattan_wrapped(Position { x: 9, y: 8 }, grid), 1 + 2);
+    assert_eq!(corner.manhattan_wrapped(Position { x: 4, y: 5 }, grid), 4 + 5);
+}
+
+#[test]
+fn test_new_game_starts_at_grid_center() {
+    for grid in [GridSize { w: 10, h: 10 }, GridSize { w: 11, h: 7 }] {
+        let state = GameState::new(grid, Seeded::new(3));
+        assert_eq!(state.snake.head(), grid.center());
+    }
+}
+
+// ---- run state transitions ----
+
+#[test]
+fn test_new_game_is_running() {
+    let g = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(1));
+    assert!(g.is_running());
+    assert!(!g.is_paused());
+    assert!(!g.is_over());
+}
+
+#[test]
+fn test_pause_and_resume_transitions() {
+    let mut g = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(1));
+    g.pause();
+    assert!(g.is_paused());
+    assert!(!g.is_running());
+    g.resume();
+    assert!(g.is_running());
+    g.resume();
+    assert!(g.is_running(), "resume while running is a no-op");
+}
+
+#[test]
+fn test_set_over_ends_game_from_any_state() {
+    let mut g = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(1));
+    g.set_over();
+    assert!(g.is_over());
+    assert!(!g.is_running());
+
+    let mut paused = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(1));
+    paused.pause();
+    paused.set_over();
+    assert!(paused.is_over());
+}
+
+#[test]
+fn test_pause_and_resume_ignored_when_over() {
+    let mut g = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(1));
+    g.set_over();
+    g.pause();
+    assert!(g.is_over(), "pause from Over is ignored");
+    g.resume();
+    assert!(g.is_over(), "resume from Over is ignored");
+    assert!(!g.is_running());
+}
+
+/// Linear congruential generator: a second RNG to pick between at runtime
+struct Lcg(u32);
+
+impl RngLike for Lcg {
+    fn next_u32(&mut self) -> u32 {
+        self.0 = self.0.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        self.0
+    }
+}
+
+#[test]
+fn test_boxed_rngs_selected_at_runtime() {
+    let grid = GridSize { w: 10, h: 10 };
+    let make_rng = |use_lcg: bool| -> Box<dyn RngLike> {
+        if use_lcg { Box::new(Lcg(7)) } else { Box::new(Seeded::new(7)) }
+    };
+
+    for use_lcg in [false, true] {
+        let mut game_state = GameState::new(grid, make_rng(use_lcg));
+        let initial_head = game_state.snake.head();
+
+        let input = ScriptedInput::new(vec![Direction::Down]);
+        let mut loop_system: GameLoop<_, _, Box<dyn RngLike>> =
+            GameLoop { input, time: MockTime::new(), rng: make_rng(use_lcg), steps_per_update: 1, on_step: None };
+        loop_system.update(&mut game_state);
+
+        assert!(!game_state.is_over());
+        assert_eq!(game_state.snake.head(), Position { x: initial_head.x, y: initial_head.y + 1 });
+    }
+
+    // Heterogeneous RNGs can live side by side in one collection
+    let mut rngs: Vec<Box<dyn RngLike>> = vec![make_rng(false), make_rng(true)];
+    assert_ne!(rngs[0].next_u32(), rngs[1].next_u32());
+}
+
+#[test]
+fn test_food_positions_match_the_food() {
+    let state = GameState::new(GridSize { w: 12, h: 12 }, Seeded::new(4));
+
+    #[cfg(not(feature = "multiple_foods"))]
+    assert_eq!(state.food_positions(), vec![state.food]);
+    #[cfg(feature = "multiple_foods")]
+    {
+        assert!(!state.foods.is_empty());
+        let expected: Vec<Position> = state.foods.iter().map(|f| f.position).collect();
+        assert_eq!(state.food_positions(), expected);
+    }
+}
+
+#[test]
+fn test_reseed_matches_fresh_game_from_seed() {
+    let grid = GridSize { w: 15, h: 15 };
+    let mut state = GameState::from_seed(grid, 3);
+    assert_eq!(state.seed, Some(3));
+    state.score = 9;
+
+    state.reseed(7);
+
+    let fresh = GameState::new(grid, Seeded::new(7));
+    assert_eq!(state.seed, Some(7));
+    assert_eq!(state.food_positions(), fresh.food_positions());
+    assert!(state.semantically_eq(&fresh));
+}
+
+#[test]
+fn test_reset_with_foreign_rng_forgets_seed() {
+    let mut state = GameState::from_seed(GridSize { w: 15, h: 15 }, 3);
+    state.reset(Seeded::new(4));
+    assert_eq!(state.seed, None);
+}
+
+#[test]
+fn test_fresh_game_has_valid_placement() {
+    for seed in 0..20 {
+        let state = GameState::new(GridSize { w: 9, h: 9 }, Seeded::new(seed));
+        assert_eq!(state.validate_placement(), Ok(()), "seed {}", seed);
+    }
+}
+
+#[test]
+fn test_played_board_with_prey_keeps_valid_placement() {
+    let dirs = [Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+    for seed in 0..10 {
+        let mut state = GameState::new(GridSize { w: 6, h: 6 }, Seeded::new(seed));
+        state.roaming_food = Some(Position { x: 0, y: 0 });
+        // A one-cell snake that never dies wanders the whole board eating
+        state.no_grow = true;
+        state.peaceful = true;
+        let mut rng = Seeded::new(seed + 100);
+
+        for tick in 0..500 {
+            state.apply_direction(dirs[rng.next_range(4) as usize]);
+            snake_game::rules::advance_roaming_food(&mut state, &mut rng);
+            snake_game::rules::step(&mut state, &mut rng);
+            assert_eq!(state.validate_placement(), Ok(()), "seed {seed}, tick {tick}");
+        }
+    }
+}
+
+#[test]
+fn test_validate_placement_reports_overlaps() {
+    let mut state = GameState::new(GridSize { w: 9, h: 9 }, Seeded::new(2));
+    let head = state.snake.head();
+    let food = state.food_positions()[0];
+
+    state.roaming_food = Some(food);
+    assert_eq!(state.validate_placement(), Err(PlacementError::FoodOverlap(food)));
+
+    state.roaming_food = Some(head);
+    assert_eq!(state.validate_placement(), Err(PlacementError::FoodOnSnake(head)));
+
+    state.roaming_food = None;
+    state.snake.body.push_back(head);
+    assert_eq!(state.validate_placement(), Err(PlacementError::SnakeOverlap(head)));
+
+    state.snake.body.push_back(Position { x: 9, y: 0 });
+    assert_eq!(state.validate_placement(), Err(PlacementError::OutOfBounds(Position { x: 9, y: 0 })));
+}
+
+#[test]
+fn test_on_step_hook_runs_after_each_step() {
+    use std::{cell::Cell, rc::Rc};
+    let mut game_state = GameState::new(GridSize { w: 20, h: 20 }, Seeded::new(42));
+    let input = ScriptedInput::new(vec![Direction::Right]);
+    let mut loop_system = GameLoop::new(input, MockTime::new(), Seeded::new(1));
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    loop_system.on_step(move |_| counter.set(counter.get() + 1));
+
+    loop_system.update(&mut game_state);
+    assert_eq!(calls.get(), 1);
+
+    // Turbo runs two steps per update, so the hook fires twice
+    loop_system.steps_per_update = 2;
+    loop_system.update(&mut game_state);
+    assert_eq!(calls.get(), 3);
+}
+
+#[test]
+fn test_lightweight_clone_plays_like_a_full_clone() {
+    let mut state = GameState::new(GridSize { w: 12, h: 12 }, Seeded::new(6));
+    let head = state.snake.head();
+    state.recent_eats.extend([Position { x: 0, y: 0 }, Position { x: 1, y: 0 }]);
+    #[cfg(not(feature = "multiple_foods"))]
+    {
+        state.food = Position { x: head.x + 1, y: head.y };
+    }
+    #[cfg(feature = "multiple_foods")]
+    {
+        state.foods[0].position = Position { x: head.x + 1, y: head.y };
+    }
+
+    let mut full = state.clone();
+    let mut light = state.lightweight_clone();
+    assert!(light.recent_eats.is_empty());
+
+    for _ in 0..3 {
+        snake_game::rules::step(&mut full, &mut Seeded::new(2));
+        snake_game::rules::step(&mut light, &mut Seeded::new(2));
+    }
+
+    assert!(full.semantically_eq(&light));
+    assert_eq!(full.tick, light.tick);
+    assert_eq!(full.last_dir, light.last_dir);
+}
+
+/// Input that reports no key presses until `pressed` is set
+struct IdleInput {
+    dir: Direction,
+    pressed: bool,
+}
+
+impl Input for IdleInput {
+    fn current_dir(&self) -> Direction {
+        self.dir
+    }
+
+    fn has_input(&self) -> bool {
+        self.pressed
+    }
+}
+
+#[test]
+fn test_wait_for_input_holds_snake_until_first_input() {
+    let mut game_state = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(42));
+    game_state.wait_for_input = true;
+    let start = game_state.snake.head();
+    let input = IdleInput { dir: Direction::Down, pressed: false };
+    let mut loop_system = GameLoop::new(input, MockTime::new(), Seeded::new(1));
+
+    for _ in 0..3 {
+        loop_system.update(&mut game_state);
+    }
+    assert_eq!(game_state.snake.head(), start);
+    assert!(!game_state.first_input_seen);
+    assert_eq!(loop_system.time.current(), 3, "time still passes while idle");
+
+    loop_system.input.pressed = true;
+    loop_system.update(&mut game_state);
+    assert!(game_state.first_input_seen);
+    assert_eq!(game_state.snake.head(), Position { x: start.x, y: start.y + 1 });
+
+    // Once started, the snake keeps moving without further presses
+    loop_system.input.pressed = false;
+    loop_system.update(&mut game_state);
+    assert_eq!(game_state.snake.head(), Position { x: start.x, y: start.y + 2 });
+
+    game_state.reset(Seeded::new(3));
+    assert!(!game_state.first_input_seen, "each round waits again");
+}
@@ -0,0 +1,78 @@
+use snake_game::{level::generate_maze, rng::Seeded, types::*};
+use std::collections::{HashSet, VecDeque};
+
+#[test]
+fn test_zero_density_yields_no_obstacles() {
+    let grid = GridSize { w: 20, h: 20 };
+    let obstacles = generate_maze(grid, &mut Seeded::new(1), 0.0);
+    assert!(obstacles.is_empty());
+}
+
+#[test]
+fn test_density_yields_roughly_that_coverage() {
+    let grid = GridSize { w: 20, h: 20 };
+    let obstacles = generate_maze(grid, &mut Seeded::new(7), 0.2);
+
+    // 400 cells minus the 5-cell protected start region
+    let eligible = 395.0;
+    let coverage = obstacles.len() as f32 / eligible;
+    assert!(
+        (0.15..=0.21).contains(&coverage),
+        "expected ~20% coverage, got {:.1}%",
+        coverage * 100.0
+    );
+
+    let unique: HashSet<Position> = obstacles.iter().copied().collect();
+    assert_eq!(unique.len(), obstacles.len(), "no duplicate obstacles");
+    assert!(obstacles.iter().all(|p| p.x >= 0 && p.y >= 0 && p.x < grid.w && p.y < grid.h));
+}
+
+#[test]
+fn test_start_region_is_always_free() {
+    let grid = GridSize { w: 11, h: 9 };
+    for seed in 0..20 {
+        let obstacles = generate_maze(grid, &mut Seeded::new(seed), 0.4);
+        let start = grid.center();
+        assert!(!obstacles.contains(&start));
+        for n in [
+            Position { x: start.x, y: start.y - 1 },
+            Position { x: start.x, y: start.y + 1 },
+            Position { x: start.x - 1, y: start.y },
+            Position { x: start.x + 1, y: start.y },
+        ] {
+            assert!(!obstacles.contains(&n));
+        }
+    }
+}
+
+#[test]
+fn test_free_cells_stay_connected() {
+    let grid = GridSize { w: 12, h: 12 };
+    let obstacles: HashSet<Position> =
+        generate_maze(grid, &mut Seeded::new(99), 0.35).into_iter().collect();
+
+    let mut seen = HashSet::from([grid.center()]);
+    let mut queue = VecDeque::from([grid.center()]);
+    while let Some(p) = queue.pop_front() {
+        for n in [
+            Position { x: p.x + 1, y: p.y },
+            Position { x: p.x - 1, y: p.y },
+            Position { x: p.x, y: p.y + 1 },
+            Position { x: p.x, y: p.y - 1 },
+        ] {
+            let in_bounds = n.x >= 0 && n.y >= 0 && n.x < grid.w && n.y < grid.h;
+            if in_bounds && !obstacles.contains(&n) && seen.insert(n) {
+                queue.push_back(n);
+            }
+        }
+    }
+    assert_eq!(seen.len(), grid.area() - obstacles.len());
+}
+
+#[test]
+fn test_maze_is_deterministic_for_seed() {
+    let grid = GridSize { w: 15, h: 15 };
+    let a = generate_maze(grid, &mut Seeded::new(5), 0.25);
+    let b = generate_maze(grid, &mut Seeded::new(5), 0.25);
+    assert_eq!(a, b);
+}
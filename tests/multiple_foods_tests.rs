@@ -55,7 +55,7 @@ fn test_normal_food_gives_1_point() {
     let mut g = GameState::new(grid, rng.clone());
 
     // Find or create a normal food next to snake head
-    let head_pos = g.snake.body[0];
+    let head_pos = g.snake.head();
     let food_pos = Position {
         x: head_pos.x + 1,
         y: head_pos.y,
@@ -66,6 +66,8 @@ fn test_normal_food_gives_1_point() {
     g.foods.push(Food {
         position: food_pos,
         food_type: FoodType::Normal,
+        growth: 1,
+        hits_remaining: 1,
     });
 
     let initial_score = g.score;
@@ -84,7 +86,7 @@ fn test_golden_food_gives_5_points() {
     let mut g = GameState::new(grid, rng.clone());
 
     // Find or create a golden food next to snake head
-    let head_pos = g.snake.body[0];
+    let head_pos = g.snake.head();
     let food_pos = Position {
         x: head_pos.x + 1,
         y: head_pos.y,
@@ -95,6 +97,8 @@ fn test_golden_food_gives_5_points() {
     g.foods.push(Food {
         position: food_pos,
         food_type: FoodType::Golden,
+        growth: 1,
+        hits_remaining: 1,
     });
 
     let initial_score = g.score;
@@ -113,7 +117,7 @@ fn test_special_food_gives_10_points() {
     let mut g = GameState::new(grid, rng.clone());
 
     // Find or create a special food next to snake head
-    let head_pos = g.snake.body[0];
+    let head_pos = g.snake.head();
     let food_pos = Position {
         x: head_pos.x + 1,
         y: head_pos.y,
@@ -124,6 +128,8 @@ fn test_special_food_gives_10_points() {
     g.foods.push(Food {
         position: food_pos,
         food_type: FoodType::Special,
+        growth: 1,
+        hits_remaining: 1,
     });
 
     let initial_score = g.score;
@@ -141,7 +147,7 @@ fn test_eating_food_removes_it_from_grid() {
     let mut rng = Seeded::new(123);
     let mut g = GameState::new(grid, rng.clone());
 
-    let head_pos = g.snake.body[0];
+    let head_pos = g.snake.head();
     let food_pos = Position {
         x: head_pos.x + 1,
         y: head_pos.y,
@@ -152,6 +158,8 @@ fn test_eating_food_removes_it_from_grid() {
     g.foods.push(Food {
         position: food_pos,
         food_type: FoodType::Normal,
+        growth: 1,
+        hits_remaining: 1,
     });
 
     let initial_food_count = g.foods.len();
@@ -173,7 +181,7 @@ fn test_new_food_spawns_after_eating() {
     let mut rng = Seeded::new(123);
     let mut g = GameState::new(grid, rng.clone());
 
-    let head_pos = g.snake.body[0];
+    let head_pos = g.snake.head();
     let food_pos = Position {
         x: head_pos.x + 1,
         y: head_pos.y,
@@ -184,18 +192,26 @@ fn test_new_food_spawns_after_eating() {
     g.foods.push(Food {
         position: food_pos,
         food_type: FoodType::Normal,
+        growth: 1,
+        hits_remaining: 1,
     });
     g.foods.push(Food {
         position: Position { x: 0, y: 0 },
         food_type: FoodType::Normal,
+        growth: 1,
+        hits_remaining: 1,
     });
     g.foods.push(Food {
         position: Position { x: 9, y: 9 },
         food_type: FoodType::Normal,
+        growth: 1,
+        hits_remaining: 1,
     });
     g.foods.push(Food {
         position: Position { x: 0, y: 9 },
         food_type: FoodType::Normal,
+        growth: 1,
+        hits_remaining: 1,
     });
 
     let initial_food_count = g.foods.len();
@@ -251,7 +267,7 @@ fn test_eating_different_foods_accumulates_score_correctly() {
     let mut score_expected = 0;
 
     // Eat a normal food (1 point)
-    let head_pos = g.snake.body[0];
+    let head_pos = g.snake.head();
     g.foods.clear();
     g.foods.push(Food {
         position: Position {
@@ -259,6 +275,8 @@ fn test_eating_different_foods_accumulates_score_correctly() {
             y: head_pos.y,
         },
         food_type: FoodType::Normal,
+        growth: 1,
+        hits_remaining: 1,
     });
     g.snake.dir = Direction::Right;
     snake_game::rules::step(&mut g, &mut rng);
@@ -266,7 +284,7 @@ fn test_eating_different_foods_accumulates_score_correctly() {
     assert_eq!(g.score, score_expected);
 
     // Eat a golden food (5 points)
-    let head_pos = g.snake.body[0];
+    let head_pos = g.snake.head();
     g.foods.clear();
     g.foods.push(Food {
         position: Position {
@@ -274,6 +292,8 @@ fn test_eating_different_foods_accumulates_score_correctly() {
             y: head_pos.y,
         },
         food_type: FoodType::Golden,
+        growth: 1,
+        hits_remaining: 1,
     });
     g.snake.dir = Direction::Right;
     snake_game::rules::step(&mut g, &mut rng);
@@ -281,7 +301,7 @@ fn test_eating_different_foods_accumulates_score_correctly() {
     assert_eq!(g.score, score_expected);
 
     // Eat a special food (10 points)
-    let head_pos = g.snake.body[0];
+    let head_pos = g.snake.head();
     g.foods.clear();
     g.foods.push(Food {
         position: Position {
@@ -289,6 +309,8 @@ fn test_eating_different_foods_accumulates_score_correctly() {
             y: head_pos.y,
         },
         food_type: FoodType::Special,
+        growth: 1,
+        hits_remaining: 1,
     });
     g.snake.dir = Direction::Right;
     snake_game::rules::step(&mut g, &mut rng);
@@ -446,20 +468,284 @@ fn test_food_types_spawn_with_correct_probabilities() {
     );
 }
 
-// Helper function to determine food type (matches the logic in rules.rs)
+// Helper function to determine food type (same weights as the spawner in state.rs)
 #[cfg(feature = "multiple_foods")]
 fn determine_food_type_helper<R: RngLike>(
     _grid: &GridSize,
     _snake: &snake_game::state::Snake,
     rng: &mut R,
 ) -> FoodType {
-    let roll = rng.next_u32() % 100;
-    if roll < 70 {
-        FoodType::Normal
-    } else if roll < 95 {
-        FoodType::Golden
-    } else {
-        FoodType::Special
+    [FoodType::Normal, FoodType::Golden, FoodType::Special][rng.weighted_index(&FOOD_WEIGHTS)]
+}
+
+#[cfg(feature = "multiple_foods")]
+#[test]
+fn test_spawned_food_types_follow_food_weights() {
+    assert_eq!(FOOD_WEIGHTS, [70, 25, 5]);
+    assert_eq!(FOOD_WEIGHTS.iter().sum::<u32>(), 100, "weights are percentages");
+
+    let mut counts = [0u32; 3];
+    for seed in 0..400 {
+        let g = GameState::new(GridSize { w: 20, h: 20 }, Seeded::new(seed));
+        for food in &g.foods {
+            let i = match food.food_type {
+                FoodType::Normal => 0,
+                FoodType::Golden => 1,
+                FoodType::Special => 2,
+            };
+            counts[i] += 1;
+        }
+    }
+
+    let total: u32 = counts.iter().sum();
+    for (count, weight) in counts.iter().zip(FOOD_WEIGHTS) {
+        let pct = count * 100 / total;
+        assert!(pct.abs_diff(weight) <= 5, "expected ~{}%, got {}%", weight, pct);
+    }
+}
+
+
+#[cfg(feature = "multiple_foods")]
+#[test]
+fn test_initial_foods_limited_by_free_cells() {
+    // A 1x3 grid has only two free cells, fewer than the minimum food count
+    let grid = GridSize { w: 1, h: 3 };
+    let g = GameState::new(grid, Seeded::new(42));
+    assert_eq!(g.foods.len(), 2);
+    assert!(!g.foods.iter().any(|f| f.position == g.snake.head()));
+}
+
+#[cfg(feature = "multiple_foods")]
+#[test]
+fn test_maintain_food_count_refills_after_bulk_removal() {
+    let grid = GridSize { w: 10, h: 10 };
+    let mut rng = Seeded::new(42);
+    let mut g = GameState::new(grid, rng.clone());
+    let before = g.foods.len();
+    g.foods.truncate(before - 3);
+    assert!(g.foods.len() < g.min_foods);
+
+    snake_game::rules::maintain_food_count(&mut g, &mut rng);
+
+    assert!(g.foods.len() >= g.min_foods);
+    for food in &g.foods {
+        assert!(!g.snake.body.contains(&food.position));
     }
 }
 
+#[cfg(feature = "multiple_foods")]
+#[test]
+fn test_eating_tops_up_to_configured_minimum() {
+    let grid = GridSize { w: 10, h: 10 };
+    let mut rng = Seeded::new(7);
+    let mut g = GameState::new(grid, rng.clone());
+    g.min_foods = 4;
+    let head = g.snake.head();
+    g.foods = vec![Food {
+        position: Position { x: head.x + 1, y: head.y },
+        food_type: FoodType::Normal,
+        growth: 1,
+        hits_remaining: 1,
+    }];
+
+    snake_game::rules::step(&mut g, &mut rng);
+
+    assert_eq!(g.score, 1);
+    assert_eq!(g.foods.len(), 4);
+}
+
+#[cfg(feature = "multiple_foods")]
+fn eat_next(g: &mut GameState, rng: &mut Seeded, food_type: FoodType) {
+    let head = g.snake.head();
+    g.foods = vec![Food {
+        position: Position { x: head.x + 1, y: head.y },
+        food_type,
+        growth: 1,
+        hits_remaining: 1,
+    }];
+    g.snake.dir = Direction::Right;
+    snake_game::rules::step(g, rng);
+}
+
+#[cfg(feature = "multiple_foods")]
+#[test]
+fn test_same_type_streak_adds_escalating_bonus() {
+    let grid = GridSize { w: 20, h: 10 };
+    let mut rng = Seeded::new(11);
+    let mut g = GameState::new(grid, rng.clone());
+    g.snake.body[0] = Position { x: 2, y: 5 };
+
+    eat_next(&mut g, &mut rng, FoodType::Normal);
+    assert_eq!(g.score, 1);
+    eat_next(&mut g, &mut rng, FoodType::Normal);
+    assert_eq!(g.score, 1 + (1 + 2));
+    eat_next(&mut g, &mut rng, FoodType::Normal);
+    assert_eq!(g.score, 1 + (1 + 2) + (1 + 3));
+    assert_eq!(g.streak_type, Some(FoodType::Normal));
+    assert_eq!(g.streak_len, 3);
+}
+
+#[cfg(feature = "multiple_foods")]
+#[test]
+fn test_switching_type_resets_streak() {
+    let grid = GridSize { w: 20, h: 10 };
+    let mut rng = Seeded::new(11);
+    let mut g = GameState::new(grid, rng.clone());
+    g.snake.body[0] = Position { x: 2, y: 5 };
+
+    eat_next(&mut g, &mut rng, FoodType::Normal);
+    eat_next(&mut g, &mut rng, FoodType::Normal);
+    let before = g.score;
+    eat_next(&mut g, &mut rng, FoodType::Golden);
+
+    assert_eq!(g.score, before + 5, "no bonus on the first food of a new streak");
+    assert_eq!(g.streak_type, Some(FoodType::Golden));
+    assert_eq!(g.streak_len, 1);
+
+    g.reset(rng.clone());
+    assert_eq!(g.streak_type, None);
+    assert_eq!(g.streak_len, 0);
+}
+
+#[cfg(feature = "multiple_foods")]
+#[test]
+fn test_all_foods_on_head_cell_are_consumed_together() {
+    let grid = GridSize { w: 10, h: 10 };
+    let mut rng = Seeded::new(5);
+    let mut g = GameState::new(grid, rng.clone());
+    let head = g.snake.head();
+    let target = Position { x: head.x + 1, y: head.y };
+    g.foods = vec![
+        Food { position: target, food_type: FoodType::Normal, growth: 1, hits_remaining: 1 },
+        Food { position: Position { x: 0, y: 0 }, food_type: FoodType::Normal, growth: 1, hits_remaining: 1 },
+        Food { position: target, food_type: FoodType::Golden, growth: 1, hits_remaining: 1 },
+    ];
+    g.snake.dir = Direction::Right;
+
+    snake_game::rules::step(&mut g, &mut rng);
+
+    assert_eq!(g.score, 1 + 5, "both stacked foods are scored");
+    assert!(!g.foods.iter().any(|f| f.position == target));
+    assert!(g.foods.len() >= g.min_foods, "count is topped back up");
+    assert_eq!(g.snake.body.len(), 2);
+}
+
+/// Snake length after each of `steps` moves right, starting with one food of `growth`
+/// directly ahead and no respawns
+#[cfg(feature = "multiple_foods")]
+fn lengths_after_eating(growth: u32, steps: usize) -> Vec<usize> {
+    let grid = GridSize { w: 20, h: 10 };
+    let mut rng = Seeded::new(2);
+    let mut g = GameState::new(grid, rng.clone());
+    g.min_foods = 0;
+    g.max_foods = 0;
+    g.snake.body[0] = Position { x: 2, y: 5 };
+    g.snake.dir = Direction::Right;
+    g.foods = vec![Food { position: Position { x: 3, y: 5 }, food_type: FoodType::Normal, growth, hits_remaining: 1 }];
+
+    (0..steps)
+        .map(|_| {
+            snake_game::rules::step(&mut g, &mut rng);
+            g.snake.body.len()
+        })
+        .collect()
+}
+
+#[cfg(feature = "multiple_foods")]
+#[test]
+fn test_growth_food_adds_segments_over_following_steps() {
+    assert_eq!(lengths_after_eating(3, 5), vec![2, 3, 4, 4, 4]);
+}
+
+#[cfg(feature = "multiple_foods")]
+#[test]
+fn test_normal_growth_adds_one_segment() {
+    assert_eq!(lengths_after_eating(1, 3), vec![2, 2, 2]);
+}
+
+#[cfg(feature = "multiple_foods")]
+#[test]
+fn test_reset_clears_pending_growth() {
+    let grid = GridSize { w: 10, h: 10 };
+    let rng = Seeded::new(2);
+    let mut g = GameState::new(grid, rng.clone());
+    g.pending_growth = 4;
+    g.reset(rng);
+    assert_eq!(g.pending_growth, 0);
+}
+
+#[cfg(feature = "multiple_foods")]
+#[test]
+fn test_armored_food_needs_two_hits() {
+    let mut rng = Seeded::new(2);
+    let mut g = GameState::new(GridSize { w: 20, h: 10 }, rng.clone());
+    g.min_foods = 0;
+    g.max_foods = 0;
+    g.snake.body[0] = Position { x: 2, y: 5 };
+    g.snake.dir = Direction::Right;
+    let target = Position { x: 3, y: 5 };
+    g.foods = vec![Food { position: target, food_type: FoodType::Normal, growth: 1, hits_remaining: 2 }];
+
+    // First contact only cracks the armor
+    snake_game::rules::step(&mut g, &mut rng);
+    assert_eq!(g.score, 0);
+    assert_eq!(g.snake.body.len(), 1);
+    assert_eq!(g.foods, vec![Food { position: target, food_type: FoodType::Normal, growth: 1, hits_remaining: 1 }]);
+
+    // Come back onto the same cell: eaten normally, and the snake survives
+    snake_game::rules::step(&mut g, &mut rng);
+    g.apply_direction(Direction::Left);
+    snake_game::rules::step(&mut g, &mut rng);
+    assert!(g.is_running());
+    assert_eq!(g.snake.head(), target);
+    assert_eq!(g.score, FoodType::Normal.point_value());
+    assert_eq!(g.snake.body.len(), 2);
+    assert!(g.foods.is_empty());
+}
+
+#[cfg(feature = "multiple_foods")]
+#[test]
+fn test_would_eat_ignores_armored_food_until_cracked() {
+    let mut rng = Seeded::new(2);
+    let mut g = GameState::new(GridSize { w: 20, h: 10 }, rng.clone());
+    g.min_foods = 0;
+    g.max_foods = 0;
+    g.snake.body[0] = Position { x: 2, y: 5 };
+    g.snake.dir = Direction::Right;
+    let target = Position { x: 3, y: 5 };
+    g.foods = vec![Food { position: target, food_type: FoodType::Normal, growth: 1, hits_remaining: 2 }];
+
+    assert!(!snake_game::rules::would_eat(&g), "the first hit only cracks the armor");
+    snake_game::rules::step(&mut g, &mut rng);
+    assert_eq!(g.score, 0);
+
+    // Turn back around onto the cracked food, which is now edible
+    snake_game::rules::step(&mut g, &mut rng);
+    g.apply_direction(Direction::Left);
+    assert!(snake_game::rules::would_eat(&g));
+}
+
+#[cfg(feature = "multiple_foods")]
+#[test]
+fn test_golden_rush_turns_every_spawn_golden_until_it_ends() {
+    let mut g = GameState::new(GridSize { w: 20, h: 20 }, Seeded::new(11));
+    let mut rng = Seeded::new(4);
+    g.tick = 30;
+    g.golden_rush_until = Some(40);
+    g.min_foods = 60;
+    g.max_foods = 60;
+    g.foods.clear();
+
+    assert!(g.in_golden_rush());
+    snake_game::rules::maintain_food_count(&mut g, &mut rng);
+    assert_eq!(g.foods.len(), 60);
+    assert!(g.foods.iter().all(|f| f.food_type == FoodType::Golden));
+
+    // At the end tick the rush is over and spawns roll their type again
+    g.tick = 40;
+    g.foods.clear();
+    assert!(!g.in_golden_rush());
+    snake_game::rules::maintain_food_count(&mut g, &mut rng);
+    let normal = g.foods.iter().filter(|f| f.food_type == FoodType::Normal).count();
+    assert!(normal > 30, "expected mostly Normal food, got {normal} of 60");
+}
@@ -36,6 +36,7 @@ fn test_round_trip_single_score() {
             score: 42,
             player_name: Some("TestPlayer".to_string()),
             timestamp: Some(1234567890),
+            duration_ticks: None,
         },
     );
     store1.save().unwrap();
@@ -62,6 +63,7 @@ fn test_round_trip_multiple_scores() {
             score: 100,
             player_name: Some("Alice".to_string()),
             timestamp: Some(1000),
+            duration_ticks: None,
         },
     );
     store1.add_score(
@@ -70,6 +72,7 @@ fn test_round_trip_multiple_scores() {
             score: 50,
             player_name: Some("Bob".to_string()),
             timestamp: Some(2000),
+            duration_ticks: None,
         },
     );
     store1.add_score(
@@ -78,6 +81,7 @@ fn test_round_trip_multiple_scores() {
             score: 75,
             player_name: Some("Charlie".to_string()),
             timestamp: Some(3000),
+            duration_ticks: None,
         },
     );
     store1.save().unwrap();
@@ -107,6 +111,7 @@ fn test_round_trip_multiple_grid_sizes() {
             score: 50,
             player_name: None,
             timestamp: None,
+            duration_ticks: None,
         },
     );
     store1.add_score(
@@ -115,6 +120,7 @@ fn test_round_trip_multiple_grid_sizes() {
             score: 100,
             player_name: None,
             timestamp: None,
+            duration_ticks: None,
         },
     );
     store1.add_score(
@@ -123,6 +129,7 @@ fn test_round_trip_multiple_grid_sizes() {
             score: 75,
             player_name: None,
             timestamp: None,
+            duration_ticks: None,
         },
     );
     store1.save().unwrap();
@@ -147,6 +154,7 @@ fn test_round_trip_optional_fields() {
             score: 100,
             player_name: None,
             timestamp: None,
+            duration_ticks: None,
         },
     );
     store1.add_score(
@@ -155,6 +163,7 @@ fn test_round_trip_optional_fields() {
             score: 50,
             player_name: Some("Player".to_string()),
             timestamp: None,
+            duration_ticks: None,
         },
     );
     store1.add_score(
@@ -163,6 +172,7 @@ fn test_round_trip_optional_fields() {
             score: 25,
             player_name: None,
             timestamp: Some(9999),
+            duration_ticks: None,
         },
     );
     store1.save().unwrap();
@@ -192,6 +202,7 @@ fn test_round_trip_incremental_updates() {
             score: 50,
             player_name: None,
             timestamp: None,
+            duration_ticks: None,
         },
     );
     store1.save().unwrap();
@@ -205,6 +216,7 @@ fn test_round_trip_incremental_updates() {
             score: 100,
             player_name: None,
             timestamp: None,
+            duration_ticks: None,
         },
     );
     store2.save().unwrap();
@@ -231,6 +243,7 @@ fn test_round_trip_max_scores_truncation() {
                 score: i * 10,
                 player_name: None,
                 timestamp: None,
+                duration_ticks: None,
             },
         );
     }
@@ -257,6 +270,7 @@ fn test_json_file_format_verification() {
             score: 42,
             player_name: Some("Test".to_string()),
             timestamp: Some(1234567890),
+            duration_ticks: None,
         },
     );
     store.save().unwrap();
@@ -296,6 +310,7 @@ fn test_round_trip_with_special_characters() {
             score: 100,
             player_name: Some("Player & Co. <test>".to_string()),
             timestamp: None,
+            duration_ticks: None,
         },
     );
     store1.save().unwrap();
@@ -310,3 +325,178 @@ fn test_round_trip_with_special_characters() {
     );
 }
 
+
+fn timed(score: u32, duration_ticks: Option<u64>) -> HighScore {
+    HighScore {
+        score,
+        player_name: None,
+        timestamp: None,
+        duration_ticks,
+    }
+}
+
+#[test]
+fn test_get_fastest_returns_minimum_duration() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("fastest.json");
+
+    let mut store = HighScoreStore::new(&path).unwrap();
+    store.add_score("10x10".to_string(), timed(50, Some(400)));
+    store.add_score("10x10".to_string(), timed(80, Some(120)));
+    store.add_score("10x10".to_string(), timed(30, Some(300)));
+
+    // Score ordering is unchanged
+    assert_eq!(store.get_highest_score("10x10"), Some(80));
+    assert_eq!(store.get_fastest("10x10"), Some(&timed(80, Some(120))));
+    assert_eq!(store.get_fastest("20x20"), None);
+
+    // Durations survive a save/load round trip
+    store.save().unwrap();
+    let reloaded = HighScoreStore::new(&path).unwrap();
+    assert_eq!(reloaded.get_fastest("10x10"), Some(&timed(80, Some(120))));
+}
+
+#[test]
+fn test_get_fastest_skips_entries_without_duration() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("untimed.json");
+
+    let mut store = HighScoreStore::new(&path).unwrap();
+    store.add_score("10x10".to_string(), timed(90, None));
+    assert_eq!(store.get_fastest("10x10"), None);
+
+    store.add_score("10x10".to_string(), timed(40, Some(999)));
+    assert_eq!(store.get_fastest("10x10"), Some(&timed(40, Some(999))));
+
+    // Missing durations are omitted from the JSON entirely
+    store.save().unwrap();
+    let json = fs::read_to_string(&path).unwrap();
+    assert_eq!(json.matches("duration_ticks").count(), 1);
+}
+
+#[test]
+fn test_add_score_and_save_persists_without_explicit_save() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("autosave.json");
+
+    let mut store = HighScoreStore::new(&path).unwrap();
+    assert_eq!(store.add_score_and_save("10x10".to_string(), timed(42, None)), Ok(true));
+
+    let reloaded = HighScoreStore::new(&path).unwrap();
+    assert_eq!(reloaded.get_highest_score("10x10"), Some(42));
+}
+
+#[test]
+fn test_add_score_and_save_reports_missing_the_top_ten() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("full_board.json");
+
+    let mut store = HighScoreStore::new(&path).unwrap();
+    for score in 1..=10 {
+        assert_eq!(store.add_score_and_save("10x10".to_string(), timed(score * 10, None)), Ok(true));
+    }
+
+    assert_eq!(store.add_score_and_save("10x10".to_string(), timed(10, None)), Ok(false));
+    assert_eq!(store.add_score_and_save("10x10".to_string(), timed(11, None)), Ok(true));
+    assert_eq!(HighScoreStore::new(&path).unwrap().get_scores("10x10").last().map(|hs| hs.score), Some(11));
+}
+
+fn stamped(score: u32, timestamp: Option<u64>) -> HighScore {
+    HighScore {
+        score,
+        player_name: None,
+        timestamp,
+        duration_ticks: None,
+    }
+}
+
+#[test]
+fn test_prune_older_than_drops_only_old_entries() {
+    let mut scores = HighScores::default();
+    scores.scores.insert(
+        "10x10".to_string(),
+        vec![stamped(90, Some(100)), stamped(80, Some(500)), stamped(70, None), stamped(60, Some(499))],
+    );
+    scores.scores.insert("20x20".to_string(), vec![stamped(50, Some(1))]);
+
+    scores.prune_older_than(500);
+
+    assert_eq!(scores.scores["10x10"], vec![stamped(80, Some(500)), stamped(70, None)]);
+    assert!(!scores.scores.contains_key("20x20"), "emptied boards are removed");
+}
+
+#[test]
+fn test_store_prune_older_than_persists() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("monthly.json");
+
+    let mut store = HighScoreStore::new(&path).unwrap();
+    store.add_score("10x10".to_string(), stamped(30, Some(10)));
+    store.add_score("10x10".to_string(), stamped(20, Some(2000)));
+    store.add_score("10x10".to_string(), stamped(10, None));
+    store.prune_older_than(1000);
+    store.save().unwrap();
+
+    let reloaded = HighScoreStore::new(&path).unwrap();
+    assert_eq!(reloaded.get_scores("10x10"), &[stamped(20, Some(2000)), stamped(10, None)]);
+}
+
+#[test]
+fn test_new_recovering_backs_up_corrupt_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("high_scores.json");
+    let garbage = "{ not json at all";
+    fs::write(&path, garbage).unwrap();
+    assert!(HighScoreStore::new(&path).is_err());
+
+    let store = HighScoreStore::new_recovering(&path).unwrap();
+
+    assert!(store.get_scores("10x10").is_empty());
+    assert!(!path.exists(), "the corrupt file is moved aside");
+    let backup = temp_dir.path().join("high_scores.json.corrupt.bak");
+    assert_eq!(fs::read_to_string(backup).unwrap(), garbage);
+}
+
+#[test]
+fn test_new_recovering_keeps_valid_scores() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("high_scores.json");
+    let mut store = HighScoreStore::new(&path).unwrap();
+    store.add_score("10x10".to_string(), timed(42, None));
+    store.save().unwrap();
+
+    let reloaded = HighScoreStore::new_recovering(&path).unwrap();
+    assert_eq!(reloaded.get_scores("10x10")[0].score, 42);
+    assert!(!temp_dir.path().join("high_scores.json.corrupt.bak").exists());
+}
+
+fn by(player: &str, score: u32) -> HighScore {
+    HighScore {
+        score,
+        player_name: Some(player.to_string()),
+        timestamp: None,
+        duration_ticks: None,
+    }
+}
+
+#[test]
+fn test_personal_best_spans_all_grids() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut store = HighScoreStore::new(temp_dir.path().join("players.json")).unwrap();
+    store.add_score("10x10".to_string(), by("ana", 40));
+    store.add_score("10x10".to_string(), by("bo", 90));
+    store.add_score("20x20".to_string(), by("ana", 75));
+    store.add_score("20x20".to_string(), timed(200, None));
+    store.add_score("30x30".to_string(), by("ana", 12));
+
+    assert_eq!(store.personal_best("ana"), Some(&by("ana", 75)));
+    assert_eq!(store.personal_best("bo"), Some(&by("bo", 90)));
+    assert_eq!(store.personal_best("cy"), None);
+
+    let ana = store.scores_by_player("ana");
+    assert_eq!(
+        ana,
+        vec![("10x10", &by("ana", 40)), ("20x20", &by("ana", 75)), ("30x30", &by("ana", 12))]
+    );
+    assert!(store.scores_by_player("cy").is_empty());
+}
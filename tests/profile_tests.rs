@@ -0,0 +1,73 @@
+//! Round-trip and validation tests for player profiles
+
+use snake_game::{
+    persistence::PersistenceError,
+    profile::Profile,
+    settings::{Palette, Settings, SettingsError},
+    types::GridSize,
+};
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_profile_round_trip() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("profiles").join("player.json");
+
+    let profile = Profile {
+        settings: Settings::new(GridSize { w: 16, h: 12 }, 20).unwrap().with_allow_reversal(true),
+        theme_name: Palette::HighContrast.name().to_string(),
+    };
+    profile.save(&path).unwrap();
+
+    let loaded = Profile::load(&path).unwrap();
+    assert_eq!(loaded, profile);
+    assert_eq!(loaded.palette(), Palette::HighContrast);
+}
+
+#[test]
+fn test_default_profile_round_trip() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("default.json");
+
+    Profile::default().save(&path).unwrap();
+    assert_eq!(Profile::load(&path).unwrap(), Profile::default());
+}
+
+#[test]
+fn test_unknown_theme_name_falls_back_to_default() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("theme.json");
+
+    let profile = Profile { theme_name: "neon".to_string(), ..Profile::default() };
+    assert_eq!(profile.palette(), Palette::Default);
+    profile.save(&path).unwrap();
+
+    let loaded = Profile::load(&path).unwrap();
+    assert_eq!(loaded.theme_name, "default");
+    assert_eq!(loaded.settings, profile.settings);
+}
+
+#[test]
+fn test_invalid_embedded_settings_rejected_on_load() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("invalid.json");
+
+    let mut profile = Profile::default();
+    profile.settings.speed = 0;
+    profile.save(&path).unwrap();
+
+    assert_eq!(
+        Profile::load(&path),
+        Err(PersistenceError::InvalidSettings(SettingsError::InvalidSpeed(0)))
+    );
+}
+
+#[test]
+fn test_malformed_profile_json_rejected() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("garbage.json");
+    fs::write(&path, "{ not json").unwrap();
+
+    assert!(matches!(Profile::load(&path), Err(PersistenceError::DeserializationError(_))));
+}
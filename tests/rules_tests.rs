@@ -1,4 +1,8 @@
-use snake_game::{rng::Seeded, state::GameState, types::*};
+use snake_game::{
+    rng::Seeded,
+    state::{GameOverReason, GameState, RunState, TurnLimit, WallBehavior, WinReason},
+    types::*,
+};
 
 #[test]
 fn test_head_moves_in_correct_direction() {
@@ -7,13 +11,13 @@ fn test_head_moves_in_correct_direction() {
 
     // Set snake direction to Right
     state.snake.dir = Direction::Right;
-    let original_pos = state.snake.body[0];
+    let original_pos = state.snake.head();
 
     snake_game::rules::step(&mut state, &mut Seeded::new(0));
 
     // Head should have moved right
     assert_eq!(
-        state.snake.body[0],
+        state.snake.head(),
         Position {
             x: original_pos.x + 1,
             y: original_pos.y
@@ -27,7 +31,7 @@ fn test_tail_is_removed_after_movement() {
     let mut state = GameState::new(grid, Seeded::new(42));
 
     // Add a second segment to the snake
-    let original_head = state.snake.body[0];
+    let original_head = state.snake.head();
     let second_pos = Position {
         x: original_head.x - 1,
         y: original_head.y,
@@ -52,13 +56,13 @@ fn test_movement_with_different_directions() {
     let grid = GridSize { w: 10, h: 10 };
     let mut state = GameState::new(grid, Seeded::new(42));
 
-    let initial_pos = state.snake.body[0];
+    let initial_pos = state.snake.head();
 
     // Test moving Up
     state.snake.dir = Direction::Up;
     snake_game::rules::step(&mut state, &mut Seeded::new(0));
     assert_eq!(
-        state.snake.body[0],
+        state.snake.head(),
         Position {
             x: initial_pos.x,
             y: initial_pos.y - 1
@@ -69,7 +73,7 @@ fn test_movement_with_different_directions() {
     state.snake.dir = Direction::Down;
     snake_game::rules::step(&mut state, &mut Seeded::new(0));
     assert_eq!(
-        state.snake.body[0],
+        state.snake.head(),
         Position {
             x: initial_pos.x,
             y: initial_pos.y
@@ -80,7 +84,7 @@ fn test_movement_with_different_directions() {
     state.snake.dir = Direction::Left;
     snake_game::rules::step(&mut state, &mut Seeded::new(0));
     assert_eq!(
-        state.snake.body[0],
+        state.snake.head(),
         Position {
             x: initial_pos.x - 1,
             y: initial_pos.y
@@ -91,7 +95,7 @@ fn test_movement_with_different_directions() {
     state.snake.dir = Direction::Right;
     snake_game::rules::step(&mut state, &mut Seeded::new(0));
     assert_eq!(
-        state.snake.body[0],
+        state.snake.head(),
         Position {
             x: initial_pos.x,
             y: initial_pos.y
@@ -106,18 +110,18 @@ fn test_snake_body_maintains_correct_sequence() {
 
     // Create a 3-segment snake in a line
     let pos2 = Position {
-        x: state.snake.body[0].x - 1,
-        y: state.snake.body[0].y,
+        x: state.snake.head().x - 1,
+        y: state.snake.head().y,
     };
     let pos3 = Position {
-        x: state.snake.body[0].x - 2,
-        y: state.snake.body[0].y,
+        x: state.snake.head().x - 2,
+        y: state.snake.head().y,
     };
     state.snake.body.push_back(pos2);
     state.snake.body.push_back(pos3);
 
     // Remember original positions
-    let original_head = state.snake.body[0];
+    let original_head = state.snake.head();
     let original_second = state.snake.body[1];
     let _original_third = state.snake.body[2];
 
@@ -127,7 +131,7 @@ fn test_snake_body_maintains_correct_sequence() {
 
     // The new head should be one position right of the original head
     assert_eq!(
-        state.snake.body[0],
+        state.snake.head(),
         Position {
             x: original_head.x + 1,
             y: original_head.y
@@ -255,7 +259,7 @@ fn test_no_teleport_movement() {
     let mut rng = Seeded::new(42);
     let mut g = GameState::new(grid, rng.clone());
 
-    let mut prev_head = g.snake.body[0];
+    let mut prev_head = g.snake.head();
 
     // Move in different directions
     for dir in [
@@ -269,7 +273,7 @@ fn test_no_teleport_movement() {
         snake_game::rules::step(&mut g, &mut rng);
 
         if !g.is_over() {
-            let new_head = g.snake.body[0];
+            let new_head = g.snake.head();
             let dx = (new_head.x - prev_head.x).abs();
             let dy = (new_head.y - prev_head.y).abs();
             let manhattan_dist = dx + dy;
@@ -311,3 +315,882 @@ fn test_eating_food_increments_score() {
         "New food should not spawn on snake"
     );
 }
+
+// ---- degenerate grids ----
+
+#[test]
+fn test_one_by_one_grid_constructs_and_ends_on_first_step() {
+    let grid = GridSize { w: 1, h: 1 };
+    let mut rng = Seeded::new(5);
+    let mut g = GameState::new(grid, rng.clone());
+    assert!(!g.is_over());
+
+    snake_game::rules::step(&mut g, &mut rng);
+
+    assert!(g.is_over(), "1x1 grid leaves the snake nowhere to go");
+    assert_eq!(g.score, 0);
+}
+
+#[test]
+#[cfg(not(feature = "multiple_foods"))]
+fn test_one_by_n_grid_filling_the_board_ends_the_game() {
+    // On a 1x2 grid the only free cell holds the food; eating it fills the board
+    let grid = GridSize { w: 1, h: 2 };
+    let mut rng = Seeded::new(9);
+    let mut g = GameState::new(grid, rng.clone());
+    assert_eq!(g.snake.head(), Position { x: 0, y: 1 });
+    assert_eq!(g.food, Position { x: 0, y: 0 });
+
+    g.snake.dir = Direction::Up;
+    snake_game::rules::step(&mut g, &mut rng);
+
+    assert_eq!(g.score, 1);
+    assert_eq!(g.snake.body.len(), 2);
+    assert!(g.is_over(), "no free cell left for food");
+}
+
+#[test]
+fn test_one_by_n_grid_sideways_move_hits_wall() {
+    let grid = GridSize { w: 1, h: 5 };
+    let mut rng = Seeded::new(3);
+    let mut g = GameState::new(grid, rng.clone());
+
+    // Default direction is Right, which is immediately out of bounds
+    snake_game::rules::step(&mut g, &mut rng);
+    assert!(g.is_over());
+}
+
+#[test]
+fn test_zero_area_grid_does_not_hang() {
+    let grid = GridSize { w: 0, h: 4 };
+    let mut rng = Seeded::new(3);
+    let mut g = GameState::new(grid, rng.clone());
+    snake_game::rules::step(&mut g, &mut rng);
+    assert!(g.is_over());
+}
+
+// ---- tick counter ----
+
+#[test]
+fn test_tick_counts_successful_moves() {
+    let grid = GridSize { w: 10, h: 10 };
+    let mut rng = Seeded::new(42);
+    let mut g = GameState::new(grid, rng.clone());
+    assert_eq!(g.tick(), 0);
+
+    for _ in 0..3 {
+        snake_game::rules::step(&mut g, &mut rng);
+    }
+    assert_eq!(g.tick(), 3);
+
+    g.pause();
+    snake_game::rules::step(&mut g, &mut rng);
+    assert_eq!(g.tick(), 3, "paused steps don't count");
+}
+
+#[test]
+fn test_tick_not_incremented_by_fatal_step_and_reset_clears_it() {
+    let grid = GridSize { w: 5, h: 5 };
+    let mut rng = Seeded::new(1);
+    let mut g = GameState::new(grid, rng.clone());
+    g.snake.body[0] = Position { x: 3, y: 2 };
+
+    snake_game::rules::step(&mut g, &mut rng); // to x = 4
+    snake_game::rules::step(&mut g, &mut rng); // into the wall
+    assert!(g.is_over());
+    assert_eq!(g.tick(), 1);
+
+    g.reset(rng.clone());
+    assert_eq!(g.tick(), 0);
+}
+
+// ---- lives ----
+
+#[test]
+fn test_lives_respawn_until_exhausted() {
+    let grid = GridSize { w: 10, h: 10 };
+    let mut rng = Seeded::new(42);
+    let mut g = GameState::new(grid, rng.clone());
+    g.lives = 2;
+    g.set_score(7);
+
+    for expected_lives in [1, 0] {
+        g.snake.body.clear();
+        g.snake.body.push_back(Position { x: 9, y: 2 });
+        g.snake.body.push_back(Position { x: 8, y: 2 });
+        g.snake.dir = Direction::Right;
+        snake_game::rules::step(&mut g, &mut rng);
+
+        assert!(!g.is_over(), "a life should absorb the crash");
+        assert_eq!(g.lives, expected_lives);
+        assert_eq!(g.snake.body.len(), 1);
+        assert_eq!(g.snake.head(), grid.center());
+        assert_eq!(g.score, 7, "score survives a respawn");
+        #[cfg(not(feature = "multiple_foods"))]
+        assert_ne!(g.food, grid.center());
+    }
+
+    g.snake.body[0] = Position { x: 9, y: 2 };
+    snake_game::rules::step(&mut g, &mut rng);
+    assert!(g.is_over(), "third crash with no lives left ends the game");
+    assert_eq!(g.score, 7);
+}
+
+#[test]
+fn test_reset_refills_lives_for_the_next_round() {
+    let grid = GridSize { w: 10, h: 10 };
+    let mut rng = Seeded::new(42);
+    let mut g = GameState::new(grid, rng.clone());
+    g.starting_lives = 1;
+    g.lives = 1;
+    let crash = |g: &mut GameState, rng: &mut Seeded| {
+        g.snake.body[0] = Position { x: 9, y: 2 };
+        g.snake.dir = Direction::Right;
+        snake_game::rules::step(g, rng);
+    };
+
+    crash(&mut g, &mut rng);
+    crash(&mut g, &mut rng);
+    assert!(g.is_over());
+    assert_eq!(g.lives, 0);
+
+    g.reset(Seeded::new(3));
+    assert_eq!(g.lives, 1);
+    crash(&mut g, &mut rng);
+    assert!(!g.is_over(), "the new round still has its life to spend");
+    assert_eq!(g.lives, 0);
+}
+
+#[test]
+fn test_lives_absorb_self_collision() {
+    let grid = GridSize { w: 10, h: 10 };
+    let mut rng = Seeded::new(1);
+    let mut g = GameState::new(grid, rng.clone());
+    g.lives = 1;
+    g.snake.body.clear();
+    g.snake.body.push_front(Position { x: 3, y: 4 });
+    g.snake.body.push_back(Position { x: 4, y: 4 });
+    g.snake.body.push_back(Position { x: 4, y: 3 });
+    g.snake.body.push_back(Position { x: 3, y: 3 });
+    g.snake.dir = Direction::Up;
+
+    snake_game::rules::step(&mut g, &mut rng);
+
+    assert!(!g.is_over());
+    assert_eq!(g.lives, 0);
+    assert_eq!(g.snake.body.len(), 1);
+}
+
+#[test]
+fn test_bounce_reflects_off_each_wall() {
+    let grid = GridSize { w: 10, h: 10 };
+    let cases = [
+        (Position { x: 5, y: 0 }, Direction::Up, Position { x: 5, y: 1 }),
+        (Position { x: 5, y: 9 }, Direction::Down, Position { x: 5, y: 8 }),
+        (Position { x: 0, y: 5 }, Direction::Left, Position { x: 1, y: 5 }),
+        (Position { x: 9, y: 5 }, Direction::Right, Position { x: 8, y: 5 }),
+    ];
+
+    for (head, dir, expected) in cases {
+        let mut g = GameState::new(grid, Seeded::new(3));
+        g.wall_behavior = WallBehavior::Bounce;
+        g.snake.body.clear();
+        g.snake.body.push_back(head);
+        g.snake.dir = dir;
+
+        snake_game::rules::step(&mut g, &mut Seeded::new(0));
+
+        assert!(!g.is_over(), "bouncing off {:?} wall should not end the game", dir);
+        assert_eq!(g.snake.dir, dir.opposite());
+        assert_eq!(g.snake.head(), expected);
+    }
+}
+
+#[test]
+fn test_bounce_with_a_body_turns_aside_and_survives() {
+    let grid = GridSize { w: 10, h: 10 };
+    let mut g = GameState::new(grid, Seeded::new(3));
+    g.wall_behavior = WallBehavior::Bounce;
+    g.snake.body.clear();
+    g.snake.body.extend([Position { x: 9, y: 5 }, Position { x: 8, y: 5 }, Position { x: 7, y: 5 }]);
+    g.snake.dir = Direction::Right;
+    place_food(&mut g, Position { x: 0, y: 0 });
+
+    // Reversing would hit the neck, so the snake turns to the first free side (Up)
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    assert!(!g.is_over());
+    assert_eq!(g.snake.head(), Position { x: 9, y: 4 });
+    assert_eq!(g.snake.dir, Direction::Up);
+    assert_eq!(g.snake.body.len(), 3);
+
+    // In a corner with Up off the grid, it takes the other side
+    let mut g = GameState::new(grid, Seeded::new(3));
+    g.wall_behavior = WallBehavior::Bounce;
+    g.snake.body.clear();
+    g.snake.body.extend([Position { x: 9, y: 0 }, Position { x: 8, y: 0 }]);
+    g.snake.dir = Direction::Right;
+    place_food(&mut g, Position { x: 0, y: 9 });
+
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    assert!(!g.is_over());
+    assert_eq!(g.snake.head(), Position { x: 9, y: 1 });
+    assert_eq!(g.snake.dir, Direction::Down);
+}
+
+#[test]
+fn test_bounce_with_every_way_blocked_is_fatal() {
+    let grid = GridSize { w: 10, h: 10 };
+    let mut g = GameState::new(grid, Seeded::new(3));
+    g.wall_behavior = WallBehavior::Bounce;
+    g.snake.body.clear();
+    // Head in the top-right corner, neck to the left and the body coiled below it
+    g.snake.body.extend([
+        Position { x: 9, y: 0 },
+        Position { x: 8, y: 0 },
+        Position { x: 8, y: 1 },
+        Position { x: 9, y: 1 },
+        Position { x: 9, y: 2 },
+    ]);
+    g.snake.dir = Direction::Right;
+
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+
+    assert!(g.is_over());
+}
+
+#[test]
+fn test_default_wall_behavior_is_die() {
+    let g = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(3));
+    assert_eq!(g.wall_behavior, WallBehavior::Die);
+}
+
+/// A single-segment snake at `head` with nothing to eat in the way
+fn graze_game(head: Position, dir: Direction) -> GameState {
+    let mut g = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(3));
+    g.snake.body.clear();
+    g.snake.body.push_back(head);
+    g.snake.dir = dir;
+    #[cfg(not(feature = "multiple_foods"))]
+    {
+        g.food = Position { x: 5, y: 5 };
+    }
+    #[cfg(feature = "multiple_foods")]
+    g.foods.clear();
+    g
+}
+
+#[test]
+fn test_graze_penalty_decays_score_along_wall() {
+    let mut g = graze_game(Position { x: 2, y: 0 }, Direction::Right);
+    g.set_score(10);
+    g.graze_penalty = Some(2);
+
+    for _ in 0..3 {
+        snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    }
+
+    assert_eq!(g.snake.head(), Position { x: 5, y: 0 });
+    assert_eq!(g.score, 10 - 3 * 2);
+
+    // The score never goes below zero
+    g.graze_penalty = Some(100);
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    assert_eq!(g.score, 0);
+}
+
+#[test]
+fn test_graze_penalty_stops_away_from_walls() {
+    let mut g = graze_game(Position { x: 1, y: 0 }, Direction::Down);
+    g.set_score(10);
+    g.graze_penalty = Some(1);
+
+    // Leaving the wall row lands on an interior cell: no decay
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    g.snake.dir = Direction::Right;
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+
+    assert_eq!(g.snake.head(), Position { x: 2, y: 2 });
+    assert_eq!(g.score, 10);
+}
+
+/// Put a single food at `pos` (the only food under `multiple_foods`)
+fn place_food(g: &mut GameState, pos: Position) {
+    #[cfg(not(feature = "multiple_foods"))]
+    {
+        g.food = pos;
+    }
+    #[cfg(feature = "multiple_foods")]
+    {
+        g.foods.clear();
+        g.foods.push(Food { position: pos, food_type: FoodType::Normal, growth: 1, hits_remaining: 1 });
+    }
+}
+
+#[test]
+fn test_food_quota_counts_each_food_and_wins_on_the_last() {
+    let mut g = GameState::new(GridSize { w: 20, h: 20 }, Seeded::new(5));
+    g.foods_remaining = Some(3);
+    let mut rng = Seeded::new(0);
+
+    for left in [2, 1, 0] {
+        let head = g.snake.head();
+        place_food(&mut g, Position { x: head.x + 1, y: head.y });
+        snake_game::rules::step(&mut g, &mut rng);
+        assert_eq!(g.foods_remaining, Some(left));
+        // A step without food leaves the count alone
+        if left > 0 {
+            let head = g.snake.head();
+            place_food(&mut g, Position { x: head.x, y: head.y + 3 });
+            snake_game::rules::step(&mut g, &mut rng);
+            assert_eq!(g.foods_remaining, Some(left));
+        }
+    }
+
+    assert_eq!(g.run_state, RunState::Won);
+    assert_eq!(g.win_reason(), Some(WinReason::QuotaCleared));
+    // Same-type streak bonuses add 2 and 3 points under `multiple_foods`
+    #[cfg(not(feature = "multiple_foods"))]
+    assert_eq!(g.score, 3);
+    #[cfg(feature = "multiple_foods")]
+    assert_eq!(g.score, 8);
+
+    let won = g.clone();
+    snake_game::rules::step(&mut g, &mut rng);
+    assert_eq!(g, won, "a won game no longer moves");
+}
+
+#[test]
+fn test_reset_refills_the_food_quota() {
+    let mut g = GameState::new(GridSize { w: 20, h: 20 }, Seeded::new(5));
+    g.food_quota = Some(3);
+    g.foods_remaining = Some(3);
+    let mut rng = Seeded::new(0);
+    let eat_ahead = |g: &mut GameState, rng: &mut Seeded| {
+        let head = g.snake.head();
+        place_food(g, Position { x: head.x + 1, y: head.y });
+        snake_game::rules::step(g, rng);
+    };
+
+    for _ in 0..3 {
+        eat_ahead(&mut g, &mut rng);
+    }
+    assert_eq!(g.run_state, RunState::Won);
+
+    g.reset(Seeded::new(6));
+    assert_eq!(g.foods_remaining, Some(3));
+    eat_ahead(&mut g, &mut rng);
+    assert!(g.is_running(), "one food doesn't clear a fresh quota");
+    assert_eq!(g.foods_remaining, Some(2));
+}
+
+#[test]
+fn test_would_eat_food_directly_ahead() {
+    let mut g = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(5));
+    let head = g.snake.head();
+    place_food(&mut g, Position { x: head.x + 1, y: head.y });
+    let before = g.clone();
+
+    assert!(snake_game::rules::would_eat(&g));
+    assert_eq!(g, before, "would_eat must not mutate the game");
+
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    assert!(g.score > 0);
+}
+
+#[test]
+fn test_would_eat_false_for_food_elsewhere_or_when_paused() {
+    let mut g = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(5));
+    let head = g.snake.head();
+    place_food(&mut g, Position { x: head.x, y: head.y + 1 });
+    assert!(!snake_game::rules::would_eat(&g));
+
+    place_food(&mut g, Position { x: head.x + 1, y: head.y });
+    g.pause();
+    assert!(!snake_game::rules::would_eat(&g));
+}
+
+#[test]
+fn test_would_eat_honors_wall_behavior() {
+    let mut g = graze_game(Position { x: 9, y: 5 }, Direction::Right);
+    place_food(&mut g, Position { x: 8, y: 5 });
+    assert!(!snake_game::rules::would_eat(&g), "the wall is fatal by default");
+
+    g.wall_behavior = WallBehavior::Bounce;
+    assert!(snake_game::rules::would_eat(&g));
+}
+
+#[test]
+fn test_turned_this_step_tracks_direction_changes() {
+    let mut g = graze_game(Position { x: 2, y: 2 }, Direction::Right);
+
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    assert!(!g.turned_this_step(), "moving straight is not a turn");
+    assert_eq!(g.last_dir, Direction::Right);
+
+    g.apply_direction(Direction::Down);
+    assert!(g.turned_this_step());
+
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    assert_eq!(g.last_dir, Direction::Down);
+    assert!(!g.turned_this_step());
+}
+
+#[test]
+fn test_max_turns_blocks_excess_turns_within_window() {
+    let mut g = graze_game(Position { x: 1, y: 1 }, Direction::Right);
+    g.max_turns = Some(TurnLimit { turns: 1, window_ticks: 4 });
+    let mut rng = Seeded::new(0);
+
+    g.apply_direction(Direction::Down);
+    snake_game::rules::step(&mut g, &mut rng);
+    assert_eq!(g.snake.head(), Position { x: 1, y: 2 });
+
+    // Budget spent: the second turn is ignored
+    g.apply_direction(Direction::Right);
+    assert_eq!(g.snake.dir, Direction::Down);
+
+    for _ in 0..3 {
+        snake_game::rules::step(&mut g, &mut rng);
+    }
+    assert_eq!(g.tick(), 4);
+
+    // The first turn has left the window
+    g.apply_direction(Direction::Right);
+    assert_eq!(g.snake.dir, Direction::Right);
+}
+
+#[test]
+fn test_peaceful_mode_holds_head_against_wall() {
+    let mut g = graze_game(Position { x: 9, y: 5 }, Direction::Right);
+    g.peaceful = true;
+    g.snake.body.push_back(Position { x: 8, y: 5 });
+
+    for _ in 0..50 {
+        snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    }
+
+    assert!(!g.is_over());
+    assert_eq!(g.snake.head(), Position { x: 9, y: 5 });
+    assert_eq!(g.snake.body.len(), 2);
+    assert_eq!(g.tick(), 0, "ignored steps are not counted");
+
+    // Turning away lets the snake move again
+    g.apply_direction(Direction::Up);
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    assert_eq!(g.snake.head(), Position { x: 9, y: 4 });
+}
+
+#[test]
+fn test_peaceful_mode_ignores_self_collision() {
+    let mut g = graze_game(Position { x: 3, y: 4 }, Direction::Up);
+    g.peaceful = true;
+    g.snake.body.push_back(Position { x: 4, y: 4 });
+    g.snake.body.push_back(Position { x: 4, y: 3 });
+    g.snake.body.push_back(Position { x: 3, y: 3 });
+    let before = g.snake.clone();
+
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+
+    assert!(!g.is_over());
+    assert_eq!(g.snake, before);
+}
+
+#[test]
+fn test_game_over_reason_records_the_collision() {
+    let mut wall = graze_game(Position { x: 9, y: 5 }, Direction::Right);
+    assert_eq!(wall.game_over_reason(), None);
+    snake_game::rules::step(&mut wall, &mut Seeded::new(0));
+    assert!(wall.is_over());
+    assert_eq!(wall.game_over_reason(), Some(GameOverReason::Wall));
+
+    let mut body = graze_game(Position { x: 3, y: 4 }, Direction::Up);
+    body.snake.body.push_back(Position { x: 4, y: 4 });
+    body.snake.body.push_back(Position { x: 4, y: 3 });
+    body.snake.body.push_back(Position { x: 3, y: 3 });
+    snake_game::rules::step(&mut body, &mut Seeded::new(0));
+    assert_eq!(body.game_over_reason(), Some(GameOverReason::SelfCollision));
+
+    body.reset(Seeded::new(1));
+    assert_eq!(body.game_over_reason(), None);
+}
+
+#[test]
+fn test_game_over_reason_unset_for_respawn_and_external_end() {
+    let mut g = graze_game(Position { x: 9, y: 5 }, Direction::Right);
+    g.lives = 1;
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    assert!(!g.is_over());
+    assert_eq!(g.game_over_reason(), None);
+
+    g.set_over();
+    assert_eq!(g.game_over_reason(), None);
+}
+
+#[test]
+fn test_roaming_food_stays_in_bounds_and_off_snake() {
+    let grid = GridSize { w: 8, h: 6 };
+    let mut g = GameState::new(grid, Seeded::new(12));
+    g.snake.body.clear();
+    for x in 1..7 {
+        g.snake.body.push_back(Position { x, y: 3 });
+    }
+    g.roaming_food = Some(Position { x: 0, y: 0 });
+    let mut rng = Seeded::new(99);
+
+    let mut visited = std::collections::HashSet::new();
+    for _ in 0..100 {
+        snake_game::rules::advance_roaming_food(&mut g, &mut rng);
+        let prey = g.roaming_food.unwrap();
+        assert!(prey.x >= 0 && prey.y >= 0 && prey.x < grid.w && prey.y < grid.h);
+        assert!(!g.snake.body.contains(&prey));
+        #[cfg(not(feature = "multiple_foods"))]
+        assert_ne!(prey, g.food);
+        visited.insert(prey);
+    }
+    assert!(visited.len() > 1, "the prey should wander");
+}
+
+#[test]
+fn test_catching_roaming_food_scores_bonus() {
+    let mut g = graze_game(Position { x: 2, y: 2 }, Direction::Right);
+    g.roaming_food = Some(Position { x: 3, y: 2 });
+
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+
+    assert_eq!(g.score, snake_game::rules::ROAMING_FOOD_POINTS);
+    assert_eq!(g.snake.body.len(), 1, "the prey gives points, not length");
+    let prey = g.roaming_food.expect("a new prey is released");
+    assert_ne!(prey, g.snake.head());
+}
+
+#[test]
+fn test_reset_moves_roaming_food_off_the_new_snake() {
+    let grid = GridSize { w: 10, h: 10 };
+    for seed in 0..50 {
+        let mut g = GameState::new(grid, Seeded::new(1));
+        g.roaming_food = Some(grid.center());
+
+        g.reset(Seeded::new(seed));
+
+        let prey = g.roaming_food.expect("the prey stays enabled");
+        assert!(!g.snake.body.contains(&prey), "seed {seed}: prey on the snake");
+        assert!(!g.food_positions().contains(&prey), "seed {seed}: prey on food");
+    }
+}
+
+#[test]
+fn test_respawn_moves_roaming_food_off_the_start_cell() {
+    let grid = GridSize { w: 10, h: 10 };
+    let mut g = GameState::new(grid, Seeded::new(42));
+    g.lives = 1;
+    g.roaming_food = Some(grid.center());
+    g.snake.body.clear();
+    g.snake.body.extend([Position { x: 9, y: 2 }, Position { x: 8, y: 2 }]);
+    g.snake.dir = Direction::Right;
+
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+
+    assert_eq!(g.snake.head(), grid.center());
+    let prey = g.roaming_food.expect("the prey is moved, not removed");
+    assert_ne!(prey, grid.center());
+    assert!(!g.food_positions().contains(&prey));
+}
+
+#[test]
+fn test_food_never_respawns_on_the_roaming_prey() {
+    // After eating, the only cells off the snake are the prey's and (4, 0)
+    let grid = GridSize { w: 5, h: 1 };
+    for seed in 0..50 {
+        let mut g = GameState::new(grid, Seeded::new(seed));
+        #[cfg(feature = "multiple_foods")]
+        {
+            g.min_foods = 1;
+            g.max_foods = 1;
+        }
+        g.snake.body = [Position { x: 1, y: 0 }, Position { x: 0, y: 0 }].into_iter().collect();
+        g.snake.dir = Direction::Right;
+        place_food(&mut g, Position { x: 2, y: 0 });
+        g.roaming_food = Some(Position { x: 3, y: 0 });
+
+        snake_game::rules::step(&mut g, &mut Seeded::new(seed));
+
+        assert!(g.is_running());
+        assert_eq!(g.food_positions(), vec![Position { x: 4, y: 0 }], "seed {seed}");
+        assert!(g.validate_placement().is_ok());
+    }
+}
+
+#[test]
+fn test_roaming_food_waits_while_paused() {
+    let mut g = graze_game(Position { x: 2, y: 2 }, Direction::Right);
+    g.roaming_food = Some(Position { x: 7, y: 7 });
+    g.pause();
+
+    snake_game::rules::advance_roaming_food(&mut g, &mut Seeded::new(0));
+    assert_eq!(g.roaming_food, Some(Position { x: 7, y: 7 }));
+}
+
+#[test]
+fn test_grid_value_factor_scales_with_area() {
+    use snake_game::rules::grid_value_factor;
+    assert_eq!(grid_value_factor(GridSize { w: 10, h: 10 }), 1);
+    assert_eq!(grid_value_factor(GridSize { w: 30, h: 30 }), 9);
+    assert_eq!(grid_value_factor(GridSize { w: 5, h: 5 }), 1, "small grids never drop below 1x");
+}
+
+#[test]
+fn test_food_value_scale_multiplies_eaten_points() {
+    let score_after_eating = |scale: bool| {
+        let mut g = GameState::new(GridSize { w: 30, h: 30 }, Seeded::new(5));
+        g.food_value_scale = scale;
+        let head = g.snake.head();
+        place_food(&mut g, Position { x: head.x + 1, y: head.y });
+        snake_game::rules::step(&mut g, &mut Seeded::new(0));
+        g.score
+    };
+
+    let base = score_after_eating(false);
+    assert!(base > 0);
+    assert_eq!(score_after_eating(true), base * 9);
+}
+
+#[test]
+fn test_bonus_zone_doubles_food_inside_only() {
+    let score_eating_at = |zone: Option<(Position, Position)>| {
+        let mut g = graze_game(Position { x: 4, y: 4 }, Direction::Right);
+        g.bonus_zone = zone;
+        place_food(&mut g, Position { x: 5, y: 4 });
+        snake_game::rules::step(&mut g, &mut Seeded::new(0));
+        g.score
+    };
+
+    let base = score_eating_at(None);
+    assert!(base > 0);
+    assert_eq!(score_eating_at(Some((Position { x: 5, y: 3 }, Position { x: 7, y: 6 }))), base * 2);
+    assert_eq!(score_eating_at(Some((Position { x: 0, y: 0 }, Position { x: 2, y: 2 }))), base);
+}
+
+#[test]
+fn test_bonus_zone_membership_includes_borders() {
+    let mut g = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(1));
+    assert!(!g.in_bonus_zone(Position { x: 0, y: 0 }), "no zone, no bonus");
+
+    // Corners may be given in either order
+    g.bonus_zone = Some((Position { x: 5, y: 5 }, Position { x: 2, y: 3 }));
+    for inside in [(2, 3), (5, 5), (2, 5), (5, 3), (3, 4)] {
+        assert!(g.in_bonus_zone(Position { x: inside.0, y: inside.1 }), "{:?}", inside);
+    }
+    for outside in [(1, 3), (6, 5), (3, 2), (3, 6)] {
+        assert!(!g.in_bonus_zone(Position { x: outside.0, y: outside.1 }), "{:?}", outside);
+    }
+}
+
+#[test]
+fn test_recent_eats_records_positions_in_order() {
+    let mut g = graze_game(Position { x: 1, y: 4 }, Direction::Right);
+    let mut eaten = Vec::new();
+    for x in 2..5 {
+        let food = Position { x, y: 4 };
+        place_food(&mut g, food);
+        snake_game::rules::step(&mut g, &mut Seeded::new(0));
+        eaten.push(food);
+    }
+    assert_eq!(g.recent_eats.iter().copied().collect::<Vec<_>>(), eaten);
+
+    g.reset(Seeded::new(1));
+    assert!(g.recent_eats.is_empty(), "reset clears the trail");
+}
+
+#[test]
+fn test_recent_eats_drops_oldest_beyond_cap() {
+    use snake_game::state::RECENT_EATS_CAP;
+    let mut g = graze_game(Position { x: 0, y: 4 }, Direction::Right);
+    for x in 1..=(RECENT_EATS_CAP as i32 + 2) {
+        place_food(&mut g, Position { x, y: 4 });
+        snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    }
+    assert_eq!(g.recent_eats.len(), RECENT_EATS_CAP);
+    assert_eq!(g.recent_eats.front(), Some(&Position { x: 3, y: 4 }));
+    assert_eq!(g.recent_eats.back(), Some(&Position { x: RECENT_EATS_CAP as i32 + 2, y: 4 }));
+}
+
+/// Length and score after eating a food on each of five steps along row 4
+fn eat_five_in_a_row(no_grow: bool) -> (usize, u32) {
+    let mut g = graze_game(Position { x: 1, y: 4 }, Direction::Right);
+    g.no_grow = no_grow;
+    for x in 2..7 {
+        place_food(&mut g, Position { x, y: 4 });
+        snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    }
+    assert!(g.is_running());
+    (g.snake.body.len(), g.score)
+}
+
+#[test]
+fn test_no_grow_scores_without_growing() {
+    let (len, score) = eat_five_in_a_row(true);
+    assert_eq!(len, 1);
+    let (_, growing_score) = eat_five_in_a_row(false);
+    assert_eq!(score, growing_score, "no_grow only affects length");
+    #[cfg(not(feature = "multiple_foods"))]
+    assert_eq!(score, 5);
+}
+
+#[test]
+fn test_growth_resumes_with_no_grow_off() {
+    let (len, _) = eat_five_in_a_row(false);
+    assert_eq!(len, 6);
+}
+
+#[test]
+fn test_trail_keeps_last_head_cells_in_order() {
+    let mut g = graze_game(Position { x: 0, y: 2 }, Direction::Right);
+    g.trail_cap = 4;
+    let mut plain = g.clone();
+    plain.trail_cap = 0;
+
+    for _ in 0..9 {
+        snake_game::rules::step(&mut g, &mut Seeded::new(0));
+        snake_game::rules::step(&mut plain, &mut Seeded::new(0));
+    }
+
+    let expected: Vec<Position> = (6..=9).map(|x| Position { x, y: 2 }).collect();
+    assert_eq!(g.trail_cells().iter().copied().collect::<Vec<_>>(), expected);
+    assert!(plain.trail_cells().is_empty(), "a zero cap records nothing");
+    assert!(g.semantically_eq(&plain), "the trail never affects gameplay");
+
+    g.reset(Seeded::new(1));
+    assert!(g.trail_cells().is_empty());
+}
+
+#[test]
+fn test_empty_snake_ends_game_instead_of_panicking() {
+    let mut g = GameState::new(GridSize { w: 10, h: 10 }, Seeded::new(1));
+    g.snake.body.clear();
+
+    assert!(!snake_game::rules::would_eat(&g));
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+
+    assert!(g.is_over());
+    assert!(g.snake.body.is_empty());
+}
+
+#[test]
+fn test_death_cell_marks_the_fatal_target() {
+    let mut wall = graze_game(Position { x: 9, y: 5 }, Direction::Right);
+    assert_eq!(wall.death_cell, None);
+    snake_game::rules::step(&mut wall, &mut Seeded::new(0));
+    assert_eq!(wall.death_cell, Some(Position { x: 10, y: 5 }));
+
+    let mut body = graze_game(Position { x: 3, y: 4 }, Direction::Up);
+    body.snake.body.push_back(Position { x: 4, y: 4 });
+    body.snake.body.push_back(Position { x: 4, y: 3 });
+    body.snake.body.push_back(Position { x: 3, y: 3 });
+    snake_game::rules::step(&mut body, &mut Seeded::new(0));
+    assert_eq!(body.death_cell, Some(Position { x: 3, y: 3 }));
+
+    body.reset(Seeded::new(1));
+    assert_eq!(body.death_cell, None);
+}
+
+#[test]
+fn test_death_cell_unset_when_a_life_is_spent() {
+    let mut g = graze_game(Position { x: 9, y: 5 }, Direction::Right);
+    g.lives = 1;
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    assert!(g.is_running());
+    assert_eq!(g.death_cell, None);
+}
+
+#[test]
+fn test_legal_dirs_single_segment_may_go_anywhere() {
+    use snake_game::rules::legal_dirs;
+    let g = graze_game(Position { x: 4, y: 4 }, Direction::Right);
+    let all = vec![Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+    assert_eq!(legal_dirs(&g, false), all);
+    assert_eq!(legal_dirs(&g, true), all);
+}
+
+#[test]
+fn test_legal_dirs_excludes_reversal() {
+    use snake_game::rules::legal_dirs;
+    let mut g = graze_game(Position { x: 4, y: 4 }, Direction::Right);
+    g.snake.body.push_back(Position { x: 3, y: 4 });
+    g.snake.body.push_back(Position { x: 2, y: 4 });
+
+    assert_eq!(legal_dirs(&g, false), vec![Direction::Up, Direction::Down, Direction::Right]);
+
+    g.allow_reversal = true;
+    assert!(legal_dirs(&g, false).contains(&Direction::Left));
+    assert!(!legal_dirs(&g, true).contains(&Direction::Left), "reversing into the neck is fatal");
+}
+
+#[test]
+fn test_legal_dirs_keeps_straight_once_turn_budget_is_spent() {
+    use snake_game::rules::legal_dirs;
+    let mut g = graze_game(Position { x: 4, y: 4 }, Direction::Right);
+    g.max_turns = Some(TurnLimit { turns: 1, window_ticks: 10 });
+
+    g.apply_direction(Direction::Down);
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+
+    assert_eq!(legal_dirs(&g, false), vec![Direction::Down]);
+    assert_eq!(legal_dirs(&g, true), vec![Direction::Down]);
+    g.apply_direction(Direction::Left);
+    assert_eq!(g.snake.dir, Direction::Down, "apply_direction agrees");
+}
+
+#[test]
+fn test_legal_dirs_strict_avoids_walls() {
+    use snake_game::rules::legal_dirs;
+    let mut g = graze_game(Position { x: 9, y: 0 }, Direction::Right);
+    g.snake.body.push_back(Position { x: 8, y: 0 });
+
+    assert_eq!(legal_dirs(&g, false), vec![Direction::Up, Direction::Down, Direction::Right]);
+    assert_eq!(legal_dirs(&g, true), vec![Direction::Down]);
+
+    // Bouncing off the top wall reflects downward; off the right wall the neck blocks the
+    // reflection, so the snake turns down instead
+    g.wall_behavior = WallBehavior::Bounce;
+    assert_eq!(legal_dirs(&g, true), vec![Direction::Up, Direction::Down, Direction::Right]);
+}
+
+// ---- public geometry helpers ----
+
+#[test]
+fn test_next_head_moves_one_cell_each_direction() {
+    use snake_game::rules::next_head;
+    let head = Position { x: 3, y: 3 };
+    assert_eq!(next_head(head, Direction::Up), Position { x: 3, y: 2 });
+    assert_eq!(next_head(head, Direction::Down), Position { x: 3, y: 4 });
+    assert_eq!(next_head(head, Direction::Left), Position { x: 2, y: 3 });
+    assert_eq!(next_head(head, Direction::Right), Position { x: 4, y: 3 });
+    // No wall handling: stepping off the grid is allowed
+    assert_eq!(next_head(Position { x: 0, y: 0 }, Direction::Up), Position { x: 0, y: -1 });
+}
+
+#[test]
+fn test_out_of_bounds_past_each_edge() {
+    use snake_game::rules::out_of_bounds;
+    let grid = GridSize { w: 5, h: 4 };
+    assert!(!out_of_bounds(Position { x: 0, y: 0 }, grid));
+    assert!(!out_of_bounds(Position { x: 4, y: 3 }, grid));
+    assert!(out_of_bounds(Position { x: 2, y: -1 }, grid));
+    assert!(out_of_bounds(Position { x: 2, y: 4 }, grid));
+    assert!(out_of_bounds(Position { x: -1, y: 2 }, grid));
+    assert!(out_of_bounds(Position { x: 5, y: 2 }, grid));
+}
+
+#[test]
+fn test_wrap_position_at_each_edge() {
+    use snake_game::rules::{next_head, wrap_position};
+    let grid = GridSize { w: 5, h: 4 };
+    let wrap_from = |p: Position, dir: Direction| wrap_position(next_head(p, dir), grid);
+
+    assert_eq!(wrap_from(Position { x: 2, y: 0 }, Direction::Up), Position { x: 2, y: 3 });
+    assert_eq!(wrap_from(Position { x: 2, y: 3 }, Direction::Down), Position { x: 2, y: 0 });
+    assert_eq!(wrap_from(Position { x: 0, y: 1 }, Direction::Left), Position { x: 4, y: 1 });
+    assert_eq!(wrap_from(Position { x: 4, y: 1 }, Direction::Right), Position { x: 0, y: 1 });
+    assert_eq!(wrap_position(Position { x: 1, y: 2 }, grid), Position { x: 1, y: 2 }, "in bounds is unchanged");
+}
@@ -2,7 +2,7 @@ use snake_game::{
     rng::Seeded,
     settings::{Settings, SettingsError, SettingsStore},
     state::GameState,
-    types::GridSize,
+    types::{Direction, GridError, GridSize, Position, MAX_GRID_AREA},
 };
 
 #[test]
@@ -11,6 +11,15 @@ fn settings_default_is_valid() {
     assert!(s.validate().is_ok());
 }
 
+#[test]
+fn settings_saved_before_the_newer_fields_still_load() {
+    let old = r#"{"grid":{"w":12,"h":8},"speed":7}"#;
+    let s: Settings = serde_json::from_str(old).expect("old settings should deserialize");
+
+    assert_eq!(s, Settings { grid: GridSize { w: 12, h: 8 }, speed: 7, ..Settings::default() });
+    assert!(s.validate().is_ok());
+}
+
 #[test]
 fn settings_invalid_grid_sizes_are_rejected() {
     let bad_w = Settings::new(GridSize { w: 0, h: 10 }, 10);
@@ -61,3 +70,102 @@ fn settings_store_persists_updates_in_memory() {
 }
 
 
+#[test]
+fn allow_reversal_defaults_to_false() {
+    assert!(!Settings::default().allow_reversal);
+    let g = Settings::default().apply_to_new_game(Seeded::new(1));
+    assert!(!g.allow_reversal);
+}
+
+fn two_segment_game(settings: Settings) -> GameState {
+    let mut g = settings.apply_to_new_game(Seeded::new(42));
+    let head = g.snake.head();
+    g.snake.body.push_back(Position { x: head.x - 1, y: head.y });
+    g.snake.dir = Direction::Right;
+    g
+}
+
+#[test]
+fn reversal_is_blocked_by_default() {
+    let mut g = two_segment_game(Settings::default());
+    g.apply_direction(Direction::Left);
+    assert_eq!(g.snake.dir, Direction::Right);
+}
+
+#[test]
+fn two_turns_between_steps_cannot_reverse() {
+    let mut g = two_segment_game(Settings::default());
+    g.apply_direction(Direction::Up);
+    g.apply_direction(Direction::Left);
+    assert_eq!(g.snake.dir, Direction::Up, "Left still reverses the last move (Right)");
+
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    assert!(!g.is_over());
+}
+
+#[test]
+fn allow_reversal_accepts_reversal_and_next_step_collides() {
+    let settings = Settings::default().with_allow_reversal(true);
+    let mut g = two_segment_game(settings);
+    assert!(g.allow_reversal);
+
+    g.apply_direction(Direction::Left);
+    assert_eq!(g.snake.dir, Direction::Left);
+
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    assert!(g.is_over(), "reversing into the neck is a self-collision");
+}
+
+#[test]
+fn food_counts_default_to_three_and_five() {
+    let s = Settings::default();
+    assert_eq!((s.min_foods, s.max_foods), (3, 5));
+}
+
+#[test]
+fn invalid_food_counts_are_rejected() {
+    let inverted = Settings::default().with_food_counts(6, 4);
+    assert!(matches!(inverted, Err(SettingsError::InvalidFoodCounts { min: 6, max: 4 })));
+
+    let zero = Settings::default().with_food_counts(0, 4);
+    assert!(matches!(zero, Err(SettingsError::InvalidFoodCounts { min: 0, max: 4 })));
+}
+
+#[cfg(feature = "multiple_foods")]
+#[test]
+fn apply_to_new_game_respects_food_counts() {
+    let s = Settings::default().with_food_counts(6, 8).unwrap();
+    let g = s.apply_to_new_game(Seeded::new(42));
+    assert_eq!((g.min_foods, g.max_foods), (6, 8));
+    assert!((6..=8).contains(&g.foods.len()), "got {} foods", g.foods.len());
+}
+
+#[test]
+fn grid_try_new_accepts_valid_grid() {
+    assert_eq!(GridSize::try_new(30, 20), Ok(GridSize { w: 30, h: 20 }));
+}
+
+#[test]
+fn grid_try_new_rejects_zero_dimension() {
+    assert_eq!(GridSize::try_new(0, 20), Err(GridError::InvalidWidth(0)));
+    assert_eq!(GridSize::try_new(20, 0), Err(GridError::InvalidHeight(0)));
+}
+
+#[test]
+fn grid_try_new_rejects_over_cap_grid() {
+    assert_eq!(
+        GridSize::try_new(1000, 1000),
+        Err(GridError::TooLarge { area: 1_000_000, max: MAX_GRID_AREA })
+    );
+    assert_eq!(
+        GridSize::try_new_capped(10, 11, 100),
+        Err(GridError::TooLarge { area: 110, max: 100 })
+    );
+    assert!(GridSize::try_new_capped(10, 10, 100).is_ok());
+}
+
+#[test]
+fn settings_reject_unrenderable_grid() {
+    let huge = Settings::new(GridSize { w: 1000, h: 1000 }, 10);
+    assert_eq!(huge, Err(SettingsError::GridTooLarge { area: 1_000_000, max: MAX_GRID_AREA }));
+}
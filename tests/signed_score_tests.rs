@@ -0,0 +1,72 @@
+#[cfg(feature = "signed_score")]
+use snake_game::{rng::Seeded, settings::Settings, state::GameState, types::*};
+
+/// Game from settings starting at `starting_score`, with one 1-point food right of the head
+#[cfg(feature = "signed_score")]
+fn game_starting_at(starting_score: i64) -> GameState {
+    let settings = Settings::default().with_starting_score(starting_score);
+    let mut g = settings.apply_to_new_game(Seeded::new(3));
+    let head = g.snake.head();
+    let ahead = Position { x: head.x + 1, y: head.y };
+    #[cfg(not(feature = "multiple_foods"))]
+    {
+        g.food = ahead;
+    }
+    #[cfg(feature = "multiple_foods")]
+    {
+        g.foods.truncate(1);
+        g.foods[0].position = ahead;
+        g.foods[0].food_type = FoodType::Normal;
+    }
+    g
+}
+
+#[cfg(feature = "signed_score")]
+#[test]
+fn test_negative_starting_score_climbs_by_eating() {
+    let mut g = game_starting_at(-5);
+    assert_eq!(g.score_i, -5);
+    assert_eq!(g.score, 0, "the unsigned mirror floors at 0");
+
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+
+    assert_eq!(g.score_i, -4);
+    assert_eq!(g.display_score(), -4);
+}
+
+#[cfg(feature = "signed_score")]
+#[test]
+fn test_penalties_push_score_below_zero() {
+    let mut g = game_starting_at(0);
+    #[cfg(not(feature = "multiple_foods"))]
+    {
+        g.food = Position { x: 0, y: 0 };
+    }
+    #[cfg(feature = "multiple_foods")]
+    g.foods.clear();
+    g.snake.body[0] = Position { x: 1, y: 9 };
+    g.snake.dir = Direction::Right;
+    g.graze_penalty = Some(3);
+
+    for _ in 0..4 {
+        snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    }
+
+    assert_eq!(g.score_i, -12);
+    assert_eq!(g.score, 0);
+
+    g.reset(Seeded::new(1));
+    assert_eq!(g.score_i, 0);
+}
+
+#[cfg(feature = "signed_score")]
+#[test]
+fn test_diff_reports_score_changes_below_zero() {
+    let before = game_starting_at(-5);
+    let mut after = before.clone();
+
+    snake_game::rules::step(&mut after, &mut Seeded::new(0));
+
+    assert_eq!(before.diff(&after).score_delta, 1);
+    assert_eq!(after.diff(&before).score_delta, -1);
+}
@@ -0,0 +1,52 @@
+use snake_game::{
+    settings::Settings,
+    sim::{simulate, sweep},
+    state::GameOverReason,
+    types::*,
+};
+
+/// Square-ish loop that eventually runs into walls on most boards
+const SCRIPT: [Direction; 6] = [
+    Direction::Right,
+    Direction::Right,
+    Direction::Down,
+    Direction::Down,
+    Direction::Left,
+    Direction::Up,
+];
+
+#[test]
+fn test_simulate_is_deterministic() {
+    let settings = Settings::default();
+    assert_eq!(simulate(&settings, 5, &SCRIPT, 200), simulate(&settings, 5, &SCRIPT, 200));
+}
+
+#[test]
+fn test_simulate_straight_line_hits_wall() {
+    let settings = Settings::default();
+    let result = simulate(&settings, 1, &[Direction::Right], 100);
+    assert_eq!(result.death_cause, Some(GameOverReason::Wall));
+    assert!(result.ticks < 100);
+}
+
+#[test]
+fn test_sweep_aggregates_every_seed() {
+    let settings = Settings::default();
+    let stats = sweep(&settings, 0..100, &SCRIPT, 300);
+
+    assert_eq!(stats.games, 100);
+    assert_eq!(stats.death_causes.values().sum::<usize>(), 100);
+    assert!(stats.min_score as f64 <= stats.mean_score && stats.mean_score <= stats.max_score as f64);
+
+    let scores: Vec<u32> = (0..100).map(|seed| simulate(&settings, seed, &SCRIPT, 300).score).collect();
+    assert_eq!(stats.max_score, *scores.iter().max().unwrap());
+    assert_eq!(stats.min_score, *scores.iter().min().unwrap());
+}
+
+#[test]
+fn test_sweep_of_no_seeds_is_empty() {
+    let stats = sweep(&Settings::default(), std::iter::empty(), &SCRIPT, 10);
+    assert_eq!(stats.games, 0);
+    assert_eq!(stats.min_score, 0);
+    assert!(stats.death_causes.is_empty());
+}
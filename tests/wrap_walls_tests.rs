@@ -17,7 +17,7 @@ fn test_wrap_right_edge() {
     snake_game::rules::step(&mut g, &mut rng);
 
     // Should wrap to left edge
-    assert_eq!(g.snake.body[0], Position { x: 0, y: 2 });
+    assert_eq!(g.snake.head(), Position { x: 0, y: 2 });
     assert!(!g.is_over(), "Game should continue when wrapping");
 }
 
@@ -35,7 +35,7 @@ fn test_wrap_left_edge() {
     snake_game::rules::step(&mut g, &mut rng);
 
     // Should wrap to right edge
-    assert_eq!(g.snake.body[0], Position { x: 4, y: 2 });
+    assert_eq!(g.snake.head(), Position { x: 4, y: 2 });
     assert!(!g.is_over(), "Game should continue when wrapping");
 }
 
@@ -53,7 +53,7 @@ fn test_wrap_top_edge() {
     snake_game::rules::step(&mut g, &mut rng);
 
     // Should wrap to bottom edge
-    assert_eq!(g.snake.body[0], Position { x: 2, y: 4 });
+    assert_eq!(g.snake.head(), Position { x: 2, y: 4 });
     assert!(!g.is_over(), "Game should continue when wrapping");
 }
 
@@ -71,7 +71,7 @@ fn test_wrap_bottom_edge() {
     snake_game::rules::step(&mut g, &mut rng);
 
     // Should wrap to top edge
-    assert_eq!(g.snake.body[0], Position { x: 2, y: 0 });
+    assert_eq!(g.snake.head(), Position { x: 2, y: 0 });
     assert!(!g.is_over(), "Game should continue when wrapping");
 }
 
@@ -101,7 +101,7 @@ fn test_wrapped_snake_never_teleports() {
     let mut rng = Seeded::new(42);
     let mut g = GameState::new_with_wrap(grid, rng.clone(), true);
 
-    let mut prev_head = g.snake.body[0];
+    let mut prev_head = g.snake.head();
 
     // Move in different directions, including wrapping scenarios
     for dir in [
@@ -120,7 +120,7 @@ fn test_wrapped_snake_never_teleports() {
         snake_game::rules::step(&mut g, &mut rng);
 
         if !g.is_over() {
-            let new_head = g.snake.body[0];
+            let new_head = g.snake.head();
 
             // Calculate Manhattan distance, accounting for wrapping
             let dx = ((new_head.x - prev_head.x).rem_euclid(grid.w)).min(
@@ -154,7 +154,7 @@ fn test_wrap_corner_wrapping() {
     g.snake.body[0] = Position { x: 4, y: 0 };
     g.snake.dir = Direction::Up;
     snake_game::rules::step(&mut g, &mut rng);
-    assert_eq!(g.snake.body[0], Position { x: 4, y: 4 });
+    assert_eq!(g.snake.head(), Position { x: 4, y: 4 });
     assert!(!g.is_over());
 
     // Bottom-left corner wrapping right
@@ -162,7 +162,7 @@ fn test_wrap_corner_wrapping() {
     g.snake.body[0] = Position { x: 0, y: 4 };
     g.snake.dir = Direction::Left;
     snake_game::rules::step(&mut g, &mut rng);
-    assert_eq!(g.snake.body[0], Position { x: 4, y: 4 });
+    assert_eq!(g.snake.head(), Position { x: 4, y: 4 });
     assert!(!g.is_over());
 }
 
@@ -208,15 +208,6 @@ fn test_food_can_spawn_after_wrapping() {
     }
 }
 
-#[cfg(feature = "wrap_walls")]
-fn wrap_position_helper(p: Position, grid: GridSize) -> Position {
-    // Helper to test wrapping logic independently
-    Position {
-        x: p.x.rem_euclid(grid.w),
-        y: p.y.rem_euclid(grid.h),
-    }
-}
-
 #[cfg(feature = "wrap_walls")]
 proptest! {
     #[test]
@@ -228,7 +219,7 @@ proptest! {
     ) {
         let grid = GridSize { w, h };
         let pos = Position { x, y };
-        let wrapped = wrap_position_helper(pos, grid);
+        let wrapped = snake_game::rules::wrap_position(pos, grid);
         
         // Wrapped position should always be in bounds
         prop_assert!(wrapped.x >= 0 && wrapped.x < grid.w);
@@ -244,7 +235,7 @@ proptest! {
     ) {
         let grid = GridSize { w, h };
         let pos = Position { x, y };
-        let wrapped = wrap_position_helper(pos, grid);
+        let wrapped = snake_game::rules::wrap_position(pos, grid);
         
         // Wrapped coordinates should be equivalent modulo grid size
         prop_assert_eq!(wrapped.x.rem_euclid(grid.w), x.rem_euclid(grid.w));
@@ -275,7 +266,7 @@ proptest! {
                 snake_game::rules::step(&mut g, &mut rng);
 
                 if !g.is_over() {
-                    let head_after = g.snake.body[0];
+                    let head_after = g.snake.head();
                     
                     // Head should always be in bounds when wrapping is enabled
                     prop_assert!(head_after.x >= 0 && head_after.x < grid.w);
@@ -305,9 +296,199 @@ proptest! {
         prop_assert!(!g.is_over(), "Wrapping should prevent wall death");
         
         // Head should be on the opposite side
-        prop_assert_eq!(g.snake.body[0].x, 0);
-        prop_assert_eq!(g.snake.body[0].y, grid.h / 2);
+        prop_assert_eq!(g.snake.head().x, 0);
+        prop_assert_eq!(g.snake.head().y, grid.h / 2);
+    }
+}
+
+
+
+#[cfg(feature = "wrap_walls")]
+#[test]
+fn test_new_with_wrap_selects_wall_behavior() {
+    use snake_game::state::WallBehavior;
+
+    let grid = GridSize { w: 10, h: 10 };
+    assert_eq!(GameState::new_with_wrap(grid, Seeded::new(1), true).wall_behavior, WallBehavior::Wrap);
+    assert_eq!(GameState::new_with_wrap(grid, Seeded::new(1), false).wall_behavior, WallBehavior::Die);
+}
+
+#[cfg(all(feature = "wrap_walls", not(feature = "multiple_foods")))]
+#[test]
+fn test_would_eat_food_across_wrapped_wall() {
+    let grid = GridSize { w: 10, h: 10 };
+    let mut g = GameState::new_with_wrap(grid, Seeded::new(2), true);
+    g.snake.body[0] = Position { x: 9, y: 4 };
+    g.snake.dir = Direction::Right;
+    g.food = Position { x: 0, y: 4 };
+
+    assert!(snake_game::rules::would_eat(&g));
+}
+
+/// A wrapping game with a single-segment snake at the right edge, heading right,
+/// and no food along its row
+#[cfg(feature = "wrap_walls")]
+fn portal_game() -> GameState {
+    let grid = GridSize { w: 10, h: 10 };
+    let mut g = GameState::new_with_wrap(grid, Seeded::new(4), true);
+    g.snake.body[0] = Position { x: 9, y: 2 };
+    g.snake.dir = Direction::Right;
+    #[cfg(not(feature = "multiple_foods"))]
+    {
+        g.food = Position { x: 5, y: 7 };
+    }
+    #[cfg(feature = "multiple_foods")]
+    g.foods.clear();
+    g
+}
+
+#[cfg(feature = "wrap_walls")]
+#[test]
+fn test_wrap_cost_charged_per_wrap() {
+    let mut g = portal_game();
+    g.set_score(5);
+    g.wrap_cost = Some(2);
+    let mut rng = Seeded::new(0);
+
+    snake_game::rules::step(&mut g, &mut rng);
+    assert_eq!(g.snake.head(), Position { x: 0, y: 2 });
+    assert_eq!(g.score, 3);
+
+    // Ordinary moves are free
+    for _ in 0..9 {
+        snake_game::rules::step(&mut g, &mut rng);
+    }
+    assert_eq!(g.score, 3);
+
+    // Second wrap, and the score floors at zero on the third
+    snake_game::rules::step(&mut g, &mut rng);
+    assert_eq!(g.score, 1);
+    for _ in 0..10 {
+        snake_game::rules::step(&mut g, &mut rng);
+    }
+    assert_eq!(g.score, 0);
+    assert!(!g.is_over());
+}
+
+#[cfg(feature = "wrap_walls")]
+#[test]
+fn test_wrap_without_cost_keeps_score() {
+    let mut g = portal_game();
+    g.set_score(5);
+
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+
+    assert_eq!(g.snake.head(), Position { x: 0, y: 2 });
+    assert_eq!(g.score, 5);
+}
+
+#[cfg(feature = "wrap_walls")]
+#[test]
+fn test_wrapping_records_last_wrap() {
+    let mut g = portal_game();
+    assert_eq!(g.last_wrap, None);
+
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    assert_eq!(g.snake.head(), Position { x: 0, y: 2 });
+    assert_eq!(g.last_wrap, Some((Direction::Right, g.tick)));
+
+    // An ordinary step keeps the record; the renderer decides when it has expired
+    let wrapped_at = g.tick;
+    snake_game::rules::step(&mut g, &mut Seeded::new(0));
+    assert_eq!(g.last_wrap, Some((Direction::Right, wrapped_at)));
+
+    g.reset(Seeded::new(1));
+    assert_eq!(g.last_wrap, None);
+}
+
+/// Wrapping game with no food in the way and `body` (head first) heading `dir`
+#[cfg(feature = "wrap_walls")]
+fn tail_chase_game(grid: GridSize, body: Vec<Position>, dir: Direction) -> GameState {
+    let mut g = GameState::new_with_wrap(grid, Seeded::new(3), true);
+    g.snake.body = body.into_iter().collect();
+    g.snake.dir = dir;
+    g.last_dir = dir;
+    #[cfg(not(feature = "multiple_foods"))]
+    {
+        // Off the snake's loop; the tests keep it clear of the cells they visit
+        g.food = Position { x: grid.w / 2, y: grid.h - 1 };
+    }
+    #[cfg(feature = "multiple_foods")]
+    {
+        g.foods.clear();
+        g.min_foods = 0;
     }
+    g
 }
 
+#[cfg(feature = "wrap_walls")]
+#[test]
+fn test_snake_chases_its_tail_round_a_wrapped_square() {
+    // A 2x2 loop split across the left/right edge of a 5x5 grid
+    let grid = GridSize { w: 5, h: 5 };
+    let a = Position { x: 4, y: 0 };
+    let b = Position { x: 0, y: 0 };
+    let c = Position { x: 0, y: 1 };
+    let d = Position { x: 4, y: 1 };
+    let mut g = tail_chase_game(grid, vec![a, d, c, b], Direction::Right);
+    let mut rng = Seeded::new(9);
+
+    for dir in [Direction::Right, Direction::Down, Direction::Left, Direction::Up].repeat(3) {
+        g.apply_direction(dir);
+        snake_game::rules::step(&mut g, &mut rng);
+        assert!(!g.is_over(), "moving {:?} into the tail's cell is safe", dir);
+        assert_eq!(g.snake.body.len(), 4);
+    }
+    assert_eq!(g.snake.head(), a);
+}
+
+#[cfg(feature = "wrap_walls")]
+#[test]
+fn test_tail_still_blocks_a_growing_step_when_wrapping() {
+    let grid = GridSize { w: 5, h: 5 };
+    let mut g = tail_chase_game(
+        grid,
+        (0..5i32).map(|i| Position { x: (2 - i).rem_euclid(5), y: 2 }).collect(),
+        Direction::Right,
+    );
+    // The tail is at (3, 2); food there means the tail stays put this step
+    #[cfg(not(feature = "multiple_foods"))]
+    {
+        g.food = Position { x: 3, y: 2 };
+    }
+    #[cfg(feature = "multiple_foods")]
+    g.foods.push(Food { position: Position { x: 3, y: 2 }, food_type: FoodType::Normal, growth: 1, hits_remaining: 1 });
 
+    snake_game::rules::step(&mut g, &mut Seeded::new(9));
+    assert!(g.is_over());
+}
+
+#[cfg(feature = "wrap_walls")]
+proptest! {
+    #[test]
+    fn prop_row_filling_snake_chases_its_tail_through_the_edge(
+        w in 2i32..12i32,
+        h in 2i32..12i32,
+        row in 0i32..12i32,
+        head_x in 0i32..12i32,
+        leftward in any::<bool>(),
+        steps in 1usize..40usize,
+    ) {
+        let grid = GridSize { w, h };
+        let row = row % h;
+        let head_x = head_x % w;
+        // Keep the food off the row the snake fills
+        let row = if row == h - 1 { 0 } else { row };
+        let (dir, back) = if leftward { (Direction::Left, 1) } else { (Direction::Right, -1) };
+        let body = (0..w).map(|i| Position { x: (head_x + back * i).rem_euclid(w), y: row }).collect();
+        let mut g = tail_chase_game(grid, body, dir);
+        let mut rng = Seeded::new(5);
+
+        for _ in 0..steps {
+            snake_game::rules::step(&mut g, &mut rng);
+            prop_assert!(!g.is_over(), "a row-long snake can always follow its tail");
+        }
+        prop_assert_eq!(g.snake.body.len(), w as usize);
+        prop_assert_eq!(g.tick(), steps as u64);
+    }
+}